@@ -1,4 +1,5 @@
 use rez_tools::config::loader::load_config;
+use rez_tools::config::AliasValue;
 use std::env;
 use std::fs;
 
@@ -28,7 +29,7 @@ fn main() {
     };
 
     // Convert to TOML
-    let toml_content = format!(
+    let mut toml_content = format!(
         r#"# rez-tools configuration file (TOML format)
 # Converted from: {}
 
@@ -49,6 +50,33 @@ tool_paths = [
             .join(",\n")
     );
 
+    if !config.aliases.is_empty() {
+        toml_content.push_str("\n# Short names that expand to a full plugin command line\n[aliases]\n");
+        let mut names: Vec<&String> = config.aliases.keys().collect();
+        names.sort();
+        for name in names {
+            match &config.aliases[name] {
+                AliasValue::String(expansion) => {
+                    toml_content.push_str(&format!(
+                        "{} = \"{}\"\n",
+                        name,
+                        expansion.replace('\\', "\\\\").replace('"', "\\\"")
+                    ));
+                }
+                AliasValue::List(tokens) => {
+                    let items = tokens
+                        .iter()
+                        .map(|token| {
+                            format!("\"{}\"", token.replace('\\', "\\\\").replace('"', "\\\""))
+                        })
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    toml_content.push_str(&format!("{} = [{}]\n", name, items));
+                }
+            }
+        }
+    }
+
     // Write to output file
     if let Err(e) = fs::write(output_path, toml_content) {
         eprintln!("Error writing output file: {}", e);
@@ -1,18 +1,25 @@
 use crate::cli::CliApp;
-use clap::{Arg, Command};
+use crate::config::Config;
+use crate::plugin::scanner::scan_plugins;
+use crate::plugin::Plugin;
+use clap::{Arg, ArgAction, Command};
+use clap_complete::Shell;
+use std::collections::HashMap;
+use std::io;
 
-/// Build the main command with global options
-pub fn build_main_command() -> Command {
-    Command::new("rt")
+/// Build the main `rt` command, registering each discovered plugin as a real
+/// subcommand so it gets its own `--help` and shows up in shell completions.
+pub fn build_main_command(plugins: &HashMap<String, Plugin>) -> Command {
+    let mut command = Command::new("rt")
         .version(env!("CARGO_PKG_VERSION"))
         .author(env!("CARGO_PKG_AUTHORS"))
-        .about("A suite tool command line for rez")
+        .about(env!("CARGO_PKG_DESCRIPTION"))
         .arg(
             Arg::new("verbose")
                 .short('v')
                 .long("verbose")
                 .help("Enable verbose logging")
-                .action(clap::ArgAction::SetTrue)
+                .action(ArgAction::SetTrue)
                 .global(true),
         )
         .arg(
@@ -20,7 +27,14 @@ pub fn build_main_command() -> Command {
                 .short('q')
                 .long("quiet")
                 .help("Suppress output")
-                .action(clap::ArgAction::SetTrue)
+                .action(ArgAction::SetTrue)
+                .global(true),
+        )
+        .arg(
+            Arg::new("lang")
+                .long("lang")
+                .help("Override the UI language (or set RT_LANG)")
+                .value_name("LOCALE")
                 .global(true),
         )
         .subcommand(
@@ -28,6 +42,159 @@ pub fn build_main_command() -> Command {
                 .about("List all available plugins")
                 .alias("ls"),
         )
+        .subcommand(
+            Command::new("install-rez")
+                .about("Install rez automatically")
+                .arg(
+                    Arg::new("standalone")
+                        .long("standalone")
+                        .help("Install rez without uv or pip, into a matching system Python or a downloaded Python Build Standalone interpreter")
+                        .action(ArgAction::SetTrue),
+                )
+                .arg(
+                    Arg::new("version")
+                        .long("version")
+                        .help("Pin rez to a PEP 440 version specifier, e.g. '==2.114.0' or '>=2.110,<3'")
+                        .value_name("SPEC"),
+                )
+                .arg(
+                    Arg::new("refresh-lock")
+                        .long("refresh-lock")
+                        .help("Re-resolve dependencies and overwrite ~/.rez-tools/rez.lock instead of installing from it")
+                        .action(ArgAction::SetTrue),
+                )
+                .arg(
+                    Arg::new("python-version")
+                        .long("python-version")
+                        .help("With --standalone, pin the bundled Python, e.g. '3.11', '>=3.11,<3.13', 'pypy3.10', or 'any'")
+                        .value_name("VERSION"),
+                ),
+        )
+        .subcommand(Command::new("check-rez").about("Check the detected rez environment"))
+        .subcommand(
+            Command::new("doctor")
+                .about("Diagnose the install toolchain (uv, pip, system python, PATH, rez)")
+                .arg(
+                    Arg::new("fix")
+                        .long("fix")
+                        .help("Attempt to bootstrap whatever is missing instead of only diagnosing")
+                        .action(ArgAction::SetTrue),
+                ),
+        )
+        .subcommand(
+            Command::new("self-update")
+                .about("Check for and install a newer rez-tools release")
+                .arg(
+                    Arg::new("check-only")
+                        .long("check-only")
+                        .help("Only report whether an update is available")
+                        .action(ArgAction::SetTrue),
+                ),
+        )
+        .subcommand(
+            Command::new("completions")
+                .about("Generate a shell completion script for rt, including installed plugins")
+                .arg(
+                    Arg::new("shell")
+                        .help("Shell to generate completions for")
+                        .value_parser(clap::value_parser!(Shell))
+                        .required_unless_present("fig"),
+                )
+                .arg(
+                    Arg::new("fig")
+                        .long("fig")
+                        .help("Generate a Fig completion spec instead of a shell script")
+                        .action(ArgAction::SetTrue)
+                        .conflicts_with("shell"),
+                ),
+        );
+
+    let mut plugin_names: Vec<&String> = plugins.keys().collect();
+    plugin_names.sort();
+    for name in plugin_names {
+        command = command.subcommand(build_plugin_subcommand(name, &plugins[name]));
+    }
+
+    command
+}
+
+/// Build the clap subcommand for a single plugin, including the flags every
+/// plugin invocation accepts
+fn build_plugin_subcommand(name: &str, plugin: &Plugin) -> Command {
+    Command::new(name.to_string())
+        .about(plugin.get_short_help())
+        .disable_help_flag(true)
+        .arg(
+            Arg::new("ignore-cmd")
+                .long("ignore-cmd")
+                .help("Ignore standard tool command when running the command")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("print")
+                .long("print")
+                .help("Print plugin details and exit")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("run-detached")
+                .long("run-detached")
+                .help("Run the command in detached mode")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("args")
+                .help("Arguments forwarded to the tool")
+                .num_args(0..)
+                .trailing_var_arg(true)
+                .allow_hyphen_values(true),
+        )
+}
+
+/// Write shell completions for `shell` to stdout, built from the live plugin set
+pub fn generate_completions(plugins: &HashMap<String, Plugin>, shell: Shell) {
+    let mut command = build_main_command(plugins);
+    let bin_name = command.get_name().to_string();
+    clap_complete::generate(shell, &mut command, bin_name, &mut io::stdout());
+}
+
+/// Write a Fig completion spec for the live plugin set to stdout
+pub fn generate_fig_completions(plugins: &HashMap<String, Plugin>) {
+    let mut command = build_main_command(plugins);
+    let bin_name = command.get_name().to_string();
+    clap_complete::generate(
+        clap_complete_fig::Fig,
+        &mut command,
+        bin_name,
+        &mut io::stdout(),
+    );
+}
+
+/// Generate a shell completion script for `shell` by scanning `config`'s
+/// `tool_paths` for plugins, rather than requiring a live `CliApp`. Tool
+/// paths differ machine to machine, so completions are always regenerated
+/// on demand from whatever is actually installed rather than baked in at
+/// build time.
+pub fn generate_completions_from_config(config: &Config, shell: Shell) -> crate::error::Result<()> {
+    let plugins = scan_plugins(
+        &config.tool_paths,
+        &config.tool_path_origins,
+        &config.extension,
+    )?;
+    generate_completions(&plugins, shell);
+    Ok(())
+}
+
+/// Generate a Fig completion spec by scanning `config`'s `tool_paths` for
+/// plugins; the standalone counterpart to [`generate_completions_from_config`]
+pub fn generate_fig_completions_from_config(config: &Config) -> crate::error::Result<()> {
+    let plugins = scan_plugins(
+        &config.tool_paths,
+        &config.tool_path_origins,
+        &config.extension,
+    )?;
+    generate_fig_completions(&plugins);
+    Ok(())
 }
 
 /// Handle the list subcommand
@@ -60,8 +227,48 @@ mod tests {
 
     #[test]
     fn test_build_main_command() {
-        let cmd = build_main_command();
+        let cmd = build_main_command(&HashMap::new());
         assert_eq!(cmd.get_name(), "rt");
         assert!(cmd.get_subcommands().any(|s| s.get_name() == "list"));
+        assert!(cmd.get_subcommands().any(|s| s.get_name() == "completions"));
+    }
+
+    #[test]
+    fn test_build_main_command_registers_plugin_subcommands() {
+        let mut plugins = HashMap::new();
+        plugins.insert(
+            "mytool".to_string(),
+            Plugin {
+                command: "mytool".to_string(),
+                name: Some("mytool".to_string()),
+                short_help: Some("My tool".to_string()),
+                requires: vec!["mytool-1".to_string()],
+                run_detached: false,
+                inherits_from: None,
+                handles_extensions: Vec::new(),
+                is_default: false,
+                file_path: Default::default(),
+            },
+        );
+
+        let cmd = build_main_command(&plugins);
+        assert!(cmd.get_subcommands().any(|s| s.get_name() == "mytool"));
+    }
+
+    #[test]
+    fn test_generate_completions_from_config_scans_tool_paths() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("mytool.rt"),
+            "command: mytool\nrequires:\n  - mytool-1\n",
+        )
+        .unwrap();
+
+        let mut config = Config::default();
+        config.tool_paths = vec![temp_dir.path().to_path_buf()];
+        config.tool_path_origins = vec![crate::config::ConfigOrigin::Default];
+
+        // Scanning and rendering should succeed now that `mytool` is on disk
+        generate_completions_from_config(&config, Shell::Bash).unwrap();
     }
 }
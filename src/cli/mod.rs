@@ -1,25 +1,65 @@
 pub mod commands;
 
 use crate::config::loader::load_config;
+use crate::config::{AliasValue, Config};
+use crate::error::RezToolsError;
+use crate::i18n::{self, Localizer};
 use crate::platform::rez_path;
+use crate::platform::timeout::CommandTimeouts;
+use crate::plugin::dispatch::resolve_for_token;
 use crate::plugin::scanner::scan_plugins;
 use crate::rez::{executor::execute_rez_command_sync, RezCommand};
 
 use log::{debug, info, warn};
 use std::collections::HashMap;
 
+/// Subcommand names that are always handled by `rt` itself and must never
+/// be shadowed by extension/default plugin dispatch
+const RESERVED_SUBCOMMANDS: &[&str] = &[
+    "list",
+    "ls",
+    "install-rez",
+    "check-rez",
+    "doctor",
+    "self-update",
+    "completions",
+    "help",
+];
+
+/// Maximum number of chained alias expansions `resolve_args` will follow
+/// before giving up, guarding against alias cycles (e.g. `a = "b"`, `b = "a"`)
+const MAX_ALIAS_DEPTH: usize = 8;
+
 /// Main CLI application
 pub struct CliApp {
     plugins: HashMap<String, crate::plugin::Plugin>,
+    aliases: HashMap<String, AliasValue>,
+    i18n: Localizer,
+    command_timeouts: CommandTimeouts,
 }
 
 impl CliApp {
-    /// Create a new CLI application
+    /// Create a new CLI application, loading configuration from the usual
+    /// layered config files
     pub fn new() -> crate::error::Result<Self> {
+        let config = load_config()?;
+        debug!("Loaded config: {:?}", config);
+
+        Self::with_config(config)
+    }
+
+    /// Create a new CLI application from an already-loaded `config`,
+    /// skipping `load_config`'s layered file discovery. Used by the library
+    /// entrypoint [`crate::run`] so an embedding caller can supply its own
+    /// configuration instead of one read from disk.
+    pub fn with_config(config: Config) -> crate::error::Result<Self> {
+        let i18n = Localizer::detect(&std::env::args().collect::<Vec<_>>());
+        debug!("Using locale: {}", i18n.locale());
+
         // Initialize rez path management
         match rez_path::find_and_set_rez_path() {
-            Ok(path) => {
-                debug!("Initialized rez path: {}", path.display());
+            Ok(install) => {
+                debug!("Initialized rez path: {}", install.path.display());
             }
             Err(e) => {
                 warn!("Could not initialize rez path: {}", e);
@@ -27,83 +67,182 @@ impl CliApp {
             }
         }
 
-        // Load configuration
-        let config = load_config()?;
-        debug!("Loaded config: {:?}", config);
-
         // Scan for plugins
-        let plugins = scan_plugins(&config.tool_paths, &config.extension)?;
+        let plugins = scan_plugins(
+            &config.tool_paths,
+            &config.tool_path_origins,
+            &config.extension,
+        )?;
         info!("Found {} plugins", plugins.len());
 
-        Ok(Self { plugins })
-    }
-
+        let command_timeouts = config.command_timeouts();
 
+        Ok(Self {
+            plugins,
+            aliases: config.aliases,
+            i18n,
+            command_timeouts,
+        })
+    }
 
-    /// Run the CLI application
-    pub async fn run(&self) -> crate::error::Result<i32> {
-        // Get command line arguments
-        let args: Vec<String> = std::env::args().collect();
-
-        if args.len() < 2 {
-            self.print_help();
-            return Ok(1);
-        }
-
-        let subcommand_name = &args[1];
+    /// Run the CLI application against `args` (as from `std::env::args()`)
+    pub async fn run(&self, args: Vec<String>) -> crate::error::Result<i32> {
+        let args = self.resolve_args(args)?;
+        let command = commands::build_main_command(&self.plugins);
+        let matches = match command.try_get_matches_from(args) {
+            Ok(matches) => matches,
+            Err(e) => {
+                // Print clap's help/version/usage output and hand back its
+                // exit code instead of letting clap exit the process
+                let _ = e.print();
+                return Ok(e.exit_code());
+            }
+        };
 
-        // Handle special commands
-        match subcommand_name.as_str() {
-            "--help" | "-h" => {
-                self.print_help();
-                return Ok(0);
+        match matches.subcommand() {
+            Some(("list", _)) => {
+                self.list_plugins();
+                Ok(0)
             }
-            "--version" | "-V" => {
-                println!("{}", env!("CARGO_PKG_VERSION"));
-                return Ok(0);
+            Some(("install-rez", sub_matches)) => {
+                let standalone = sub_matches.get_flag("standalone");
+                let version_spec = sub_matches.get_one::<String>("version").map(String::as_str);
+                let refresh_lock = sub_matches.get_flag("refresh-lock");
+                let python_version = sub_matches
+                    .get_one::<String>("python-version")
+                    .map(String::as_str);
+                self.handle_install_rez(standalone, version_spec, refresh_lock, python_version)
+                    .await
             }
-            "list" => {
-                self.list_plugins();
-                return Ok(0);
+            Some(("check-rez", _)) => self.handle_check_rez(),
+            Some(("doctor", sub_matches)) => {
+                let fix = sub_matches.get_flag("fix");
+                self.handle_doctor(fix).await
+            }
+            Some(("self-update", sub_matches)) => {
+                let check_only = sub_matches.get_flag("check-only");
+                self.handle_self_update(check_only).await
             }
-            "install-rez" => {
-                return self.handle_install_rez().await;
+            Some(("completions", sub_matches)) => {
+                if sub_matches.get_flag("fig") {
+                    commands::generate_fig_completions(&self.plugins);
+                } else {
+                    let shell = *sub_matches
+                        .get_one::<clap_complete::Shell>("shell")
+                        .expect("shell is required unless --fig is set");
+                    commands::generate_completions(&self.plugins, shell);
+                }
+                Ok(0)
             }
-            "check-rez" => {
-                return self.handle_check_rez();
+            Some((name, sub_matches)) => {
+                if let Some(plugin) = self.plugins.get(name) {
+                    self.handle_plugin_command(plugin, sub_matches)
+                } else {
+                    eprintln!("Unknown command: {}", name);
+                    self.print_help();
+                    Ok(1)
+                }
+            }
+            None => {
+                self.print_help();
+                Ok(1)
             }
-            _ => {}
-        }
-
-        // Check if it's a plugin command
-        if let Some(plugin) = self.plugins.get(subcommand_name) {
-            self.handle_plugin_command(plugin, &args[2..])
-        } else {
-            eprintln!("Unknown command: {}", subcommand_name);
-            self.print_help();
-            Ok(1)
         }
     }
 
-    /// Handle a plugin command execution
-    fn handle_plugin_command(&self, plugin: &crate::plugin::Plugin, args: &[String]) -> crate::error::Result<i32> {
-        let mut ignore_cmd = false;
-        let mut run_detached = false;
-        let mut print_details = false;
-        let mut remaining_args = Vec::new();
+    /// Rewrite argv so that, before any "unknown command" handling, aliases
+    /// and unrecognized tokens are resolved into a real plugin invocation:
+    ///
+    /// - if the first argument is a user-defined alias (`[aliases]` in the
+    ///   config), it is expanded into tokens (shell-tokenized if the alias
+    ///   is a string, taken verbatim if it's a list) and spliced in place
+    ///   of the alias name, then re-checked so aliases may expand to other
+    ///   aliases, up to `MAX_ALIAS_DEPTH` levels deep. An alias that expands
+    ///   back into itself, directly or transitively, is rejected with a
+    ///   `ConfigError` listing the full chain rather than looping forever;
+    /// - otherwise, if it is a file or unrecognized token that a plugin
+    ///   claims by extension or `is_default`, it is replaced with that
+    ///   plugin's name, forwarding the original token through as an argument.
+    ///
+    /// Known subcommands, flags, and plugin names are left alone.
+    fn resolve_args(&self, args: Vec<String>) -> crate::error::Result<Vec<String>> {
+        let mut args = args;
+        let mut chain: Vec<String> = Vec::new();
+
+        for _ in 0..=MAX_ALIAS_DEPTH {
+            let Some(token) = args.get(1).cloned() else {
+                return Ok(args);
+            };
+
+            if token.starts_with('-')
+                || RESERVED_SUBCOMMANDS.contains(&token.as_str())
+                || self.plugins.contains_key(&token)
+            {
+                return Ok(args);
+            }
 
-        // Parse arguments manually
-        let mut i = 0;
-        while i < args.len() {
-            match args[i].as_str() {
-                "--ignore-cmd" => ignore_cmd = true,
-                "--run-detached" => run_detached = true,
-                "--print" => print_details = true,
-                _ => remaining_args.push(args[i].clone()),
+            if let Some(expansion) = self.aliases.get(&token) {
+                if chain.contains(&token) {
+                    chain.push(token);
+                    return Err(RezToolsError::ConfigError(format!(
+                        "Alias cycle detected: {}",
+                        chain.join(" -> ")
+                    )));
+                }
+                chain.push(token.clone());
+
+                let tokens = match expansion {
+                    AliasValue::String(expansion) => tokenize_command_line(expansion),
+                    AliasValue::List(tokens) => tokens.clone(),
+                };
+                if tokens.is_empty() {
+                    return Ok(args);
+                }
+
+                debug!("Expanding alias '{}' into {:?}", token, tokens);
+                let mut rewritten = Vec::with_capacity(args.len() - 2 + tokens.len());
+                rewritten.push(args[0].clone());
+                rewritten.extend(tokens);
+                rewritten.extend(args.into_iter().skip(2));
+                args = rewritten;
+                continue;
             }
-            i += 1;
+
+            let Some(plugin) = resolve_for_token(&self.plugins, &token) else {
+                return Ok(args);
+            };
+
+            let name = plugin.get_name();
+            debug!("Dispatching unrecognized token '{}' to plugin '{}'", token, name);
+
+            let mut rewritten = Vec::with_capacity(args.len() + 1);
+            rewritten.push(args[0].clone());
+            rewritten.push(name);
+            rewritten.extend(args.into_iter().skip(1));
+            return Ok(rewritten);
         }
 
+        Err(RezToolsError::ConfigError(format!(
+            "Alias expansion did not settle within {} levels: {}",
+            MAX_ALIAS_DEPTH,
+            chain.join(" -> ")
+        )))
+    }
+
+    /// Handle a plugin command execution
+    fn handle_plugin_command(
+        &self,
+        plugin: &crate::plugin::Plugin,
+        matches: &clap::ArgMatches,
+    ) -> crate::error::Result<i32> {
+        let ignore_cmd = matches.get_flag("ignore-cmd");
+        let run_detached = matches.get_flag("run-detached");
+        let print_details = matches.get_flag("print");
+        let remaining_args: Vec<String> = matches
+            .get_many::<String>("args")
+            .map(|values| values.cloned().collect())
+            .unwrap_or_default();
+
         // Handle --print option
         if print_details {
             self.print_plugin_details(plugin);
@@ -120,6 +259,10 @@ impl CliApp {
 
         if run_detached {
             rez_cmd = rez_cmd.with_detached(true);
+        } else {
+            // Replace the `rt` process with the rez command on Unix so
+            // there's no extra layer forwarding signals imperfectly.
+            rez_cmd = rez_cmd.with_replace_process(true);
         }
 
         // Handle additional arguments
@@ -136,29 +279,41 @@ impl CliApp {
         println!("rt {}", env!("CARGO_PKG_VERSION"));
         println!("{}", env!("CARGO_PKG_DESCRIPTION"));
         println!();
-        println!("USAGE:");
-        println!("    rt [OPTIONS] <COMMAND> [ARGS]...");
+        println!("{}", self.i18n.tr("cli-usage-heading"));
+        println!("    {}", self.i18n.tr("cli-usage-line"));
         println!();
-        println!("OPTIONS:");
-        println!("    -h, --help       Print help information");
-        println!("    -V, --version    Print version information");
+        println!("{}", self.i18n.tr("cli-options-heading"));
+        println!("    {}", self.i18n.tr("cli-option-help"));
+        println!("    {}", self.i18n.tr("cli-option-version"));
+        println!("    {}", self.i18n.tr("cli-option-lang"));
         println!();
-        println!("COMMANDS:");
-        println!("    list             List all available plugins");
+        println!("{}", self.i18n.tr("cli-commands-heading"));
+        println!("    {}", self.i18n.tr("cli-command-list"));
+        println!("    {}", self.i18n.tr("cli-command-install-rez"));
+        println!("        {}", self.i18n.tr("cli-command-install-rez-standalone"));
+        println!("        {}", self.i18n.tr("cli-command-install-rez-version"));
+        println!("        {}", self.i18n.tr("cli-command-install-rez-refresh-lock"));
+        println!("        {}", self.i18n.tr("cli-command-install-rez-python-version"));
+        println!("    {}", self.i18n.tr("cli-command-check-rez"));
+        println!("    {}", self.i18n.tr("cli-command-doctor"));
+        println!("        {}", self.i18n.tr("cli-command-doctor-fix"));
+        println!("    {}", self.i18n.tr("cli-command-self-update"));
+        println!("        {}", self.i18n.tr("cli-command-self-update-check-only"));
+        println!("    {}", self.i18n.tr("cli-command-completions"));
 
         if !self.plugins.is_empty() {
             println!();
-            println!("PLUGIN COMMANDS:");
+            println!("{}", self.i18n.tr("cli-plugin-commands-heading"));
             for (name, plugin) in &self.plugins {
                 println!("    {:<20} {}", name, plugin.get_short_help());
             }
         }
 
         println!();
-        println!("PLUGIN OPTIONS:");
-        println!("    --ignore-cmd     Ignore standard tool command when running the command");
-        println!("    --print          Print plugin details and exit");
-        println!("    --run-detached   Run the command in detached mode");
+        println!("{}", self.i18n.tr("cli-plugin-options-heading"));
+        println!("    {}", self.i18n.tr("cli-plugin-option-ignore-cmd"));
+        println!("    {}", self.i18n.tr("cli-plugin-option-print"));
+        println!("    {}", self.i18n.tr("cli-plugin-option-run-detached"));
     }
 
     /// Print plugin details as JSON
@@ -172,30 +327,138 @@ impl CliApp {
     /// List all available plugins
     pub fn list_plugins(&self) {
         if self.plugins.is_empty() {
-            println!("No plugins found.");
+            println!("{}", self.i18n.tr("list-no-plugins"));
             return;
         }
 
-        println!("Available plugins:");
+        println!("{}", self.i18n.tr("list-available-plugins"));
         for (name, plugin) in &self.plugins {
             println!("  {:<20} {}", name, plugin.get_short_help());
         }
     }
 
     /// Handle rez installation
-    async fn handle_install_rez(&self) -> crate::error::Result<i32> {
+    async fn handle_install_rez(
+        &self,
+        standalone: bool,
+        version_spec: Option<&str>,
+        refresh_lock: bool,
+        python_version: Option<&str>,
+    ) -> crate::error::Result<i32> {
         use crate::platform::installer;
 
-        println!("Installing rez...");
-        match installer::install_rez().await {
+        if standalone {
+            println!("{}", self.i18n.tr("install-rez-standalone-installing"));
+            return match installer::install_rez_standalone(
+                version_spec,
+                refresh_lock,
+                python_version,
+                self.command_timeouts,
+            )
+            .await
+            {
+                Ok(rez_tools_dir) => {
+                    let wrapper = if cfg!(windows) { "rez.bat" } else { "rez" };
+                    rez_path::set_rez_path(rez_tools_dir.join("bin").join(wrapper));
+                    println!("{}", self.i18n.tr("install-rez-success"));
+                    println!("{}", self.i18n.tr("install-rez-hint"));
+                    Ok(0)
+                }
+                Err(e) => {
+                    eprintln!(
+                        "{}",
+                        self.i18n
+                            .tr_args("install-rez-failure", Some(&i18n::arg("error", e.to_string())))
+                    );
+                    eprintln!("{}", self.i18n.tr("install-rez-failure-hint"));
+                    Ok(1)
+                }
+            };
+        }
+
+        println!("{}", self.i18n.tr("install-rez-installing"));
+        match installer::install_rez(version_spec, refresh_lock, self.command_timeouts).await {
             Ok(()) => {
-                println!("‚úÖ Rez installed successfully!");
-                println!("You can now use rez commands through rt.");
+                println!("{}", self.i18n.tr("install-rez-success"));
+                println!("{}", self.i18n.tr("install-rez-hint"));
                 Ok(0)
             }
             Err(e) => {
-                eprintln!("‚ùå Failed to install rez: {}", e);
-                eprintln!("Please install rez manually or check the documentation.");
+                eprintln!(
+                    "{}",
+                    self.i18n
+                        .tr_args("install-rez-failure", Some(&i18n::arg("error", e.to_string())))
+                );
+                eprintln!("{}", self.i18n.tr("install-rez-failure-hint"));
+                Ok(1)
+            }
+        }
+    }
+
+    /// Handle self-update: check for a newer release and optionally install it
+    async fn handle_self_update(&self, check_only: bool) -> crate::error::Result<i32> {
+        use crate::selfupdate::SelfUpdater;
+
+        let updater = SelfUpdater::default();
+
+        println!("{}", self.i18n.tr("self-update-checking"));
+        let check = match updater.check_for_update().await {
+            Ok(check) => check,
+            Err(e) => {
+                eprintln!(
+                    "{}",
+                    self.i18n.tr_args(
+                        "self-update-check-failure",
+                        Some(&i18n::arg("error", e.to_string()))
+                    )
+                );
+                return Ok(1);
+            }
+        };
+
+        if !check.update_available {
+            println!(
+                "{}",
+                self.i18n.tr_args(
+                    "self-update-up-to-date",
+                    Some(&i18n::arg("version", check.current_version.clone()))
+                )
+            );
+            return Ok(0);
+        }
+
+        let mut available_args = fluent_bundle::FluentArgs::new();
+        available_args.set("current", check.current_version.clone());
+        available_args.set("latest", check.latest_version.clone());
+        println!(
+            "{}",
+            self.i18n.tr_args("self-update-available", Some(&available_args))
+        );
+
+        if check_only {
+            return Ok(0);
+        }
+
+        println!("{}", self.i18n.tr("self-update-downloading"));
+        match updater.apply_update(&check).await {
+            Ok(()) => {
+                println!(
+                    "{}",
+                    self.i18n.tr_args(
+                        "self-update-success",
+                        Some(&i18n::arg("version", check.latest_version.clone()))
+                    )
+                );
+                Ok(0)
+            }
+            Err(e) => {
+                eprintln!(
+                    "{}",
+                    self.i18n.tr_args(
+                        "self-update-failure",
+                        Some(&i18n::arg("error", e.to_string()))
+                    )
+                );
                 Ok(1)
             }
         }
@@ -205,49 +468,269 @@ impl CliApp {
     fn handle_check_rez(&self) -> crate::error::Result<i32> {
         use crate::platform::detection;
 
-        println!("Checking rez environment...");
+        println!("{}", self.i18n.tr("check-rez-checking"));
 
         // Show REZ_PATH environment variable
         if let Ok(rez_path_env) = std::env::var("REZ_PATH") {
-            println!("üîß REZ_PATH: {}", rez_path_env);
+            println!(
+                "{}",
+                self.i18n
+                    .tr_args("check-rez-path-env", Some(&i18n::arg("path", rez_path_env)))
+            );
         }
 
         // Show unified rez path
         match rez_path::get_rez_path() {
             Ok(path) => {
-                println!("üéØ Unified rez path: {}", path.display());
+                println!(
+                    "{}",
+                    self.i18n.tr_args(
+                        "check-rez-unified-path",
+                        Some(&i18n::arg("path", path.display().to_string()))
+                    )
+                );
             }
             Err(e) => {
-                println!("‚ö†Ô∏è  Could not determine rez path: {}", e);
+                println!(
+                    "{}",
+                    self.i18n.tr_args(
+                        "check-rez-unified-path-error",
+                        Some(&i18n::arg("error", e.to_string()))
+                    )
+                );
             }
         }
 
         match detection::detect_rez_environment() {
             Ok(env) => {
                 if env.is_installed {
-                    println!("‚úÖ Rez is installed");
+                    println!("{}", self.i18n.tr("check-rez-installed"));
                     if let Some(ref version) = env.version {
-                        println!("   Version: {}", version);
+                        println!(
+                            "   {}",
+                            self.i18n.tr_args(
+                                "check-rez-version",
+                                Some(&i18n::arg("version", version.clone()))
+                            )
+                        );
                     }
                     if let Some(ref rez_path) = env.rez_path {
-                        println!("   Detected path: {}", rez_path.display());
+                        println!(
+                            "   {}",
+                            self.i18n.tr_args(
+                                "check-rez-detected-path",
+                                Some(&i18n::arg("path", rez_path.display().to_string()))
+                            )
+                        );
                     }
                     if !env.packages_path.is_empty() {
-                        println!("   Package paths:");
+                        println!("   {}", self.i18n.tr("check-rez-package-paths"));
                         for path in &env.packages_path {
                             println!("     - {}", path.display());
                         }
                     }
                 } else {
-                    println!("‚ùå Rez is not installed");
-                    println!("Run 'rt install-rez' to install rez automatically.");
+                    println!("{}", self.i18n.tr("check-rez-not-installed"));
+                    println!("{}", self.i18n.tr("check-rez-not-installed-hint"));
+                }
+                Ok(0)
+            }
+            Err(e) => {
+                eprintln!(
+                    "{}",
+                    self.i18n
+                        .tr_args("check-rez-error", Some(&i18n::arg("error", e.to_string())))
+                );
+                Ok(1)
+            }
+        }
+    }
+
+    /// Handle `rt doctor`: probe the install toolchain and report each
+    /// piece as OK/missing/broken, optionally attempting to fix what it can
+    async fn handle_doctor(&self, fix: bool) -> crate::error::Result<i32> {
+        use crate::platform::doctor::{self, ProbeStatus};
+
+        println!("{}", self.i18n.tr("doctor-checking"));
+
+        let diagnostics = doctor::run_diagnostics(self.command_timeouts).await;
+        let mut all_ok = true;
+
+        for probe in &diagnostics {
+            let (icon, status_word) = match probe.status {
+                ProbeStatus::Ok => ("✅", "OK"),
+                ProbeStatus::Missing => ("❌", "MISSING"),
+                ProbeStatus::Broken => ("⚠️", "BROKEN"),
+            };
+            if probe.status != ProbeStatus::Ok {
+                all_ok = false;
+            }
+
+            println!("{} {} [{}]", icon, probe.name, status_word);
+            if let Some(ref detail) = probe.detail {
+                println!("     {}", detail);
+            }
+            if let Some(ref remediation) = probe.remediation {
+                println!("     -> {}", remediation);
+            }
+        }
+
+        if all_ok {
+            println!("{}", self.i18n.tr("doctor-all-ok"));
+            return Ok(0);
+        }
+
+        if !fix {
+            println!("{}", self.i18n.tr("doctor-fix-hint"));
+            return Ok(1);
+        }
+
+        println!("{}", self.i18n.tr("doctor-fixing"));
+        match doctor::fix(&diagnostics, self.command_timeouts).await {
+            Ok(actions) if actions.is_empty() => {
+                println!("{}", self.i18n.tr("doctor-fix-nothing-to-do"));
+                Ok(0)
+            }
+            Ok(actions) => {
+                for action in actions {
+                    println!("   - {}", action);
                 }
                 Ok(0)
             }
             Err(e) => {
-                eprintln!("‚ùå Error checking rez environment: {}", e);
+                eprintln!(
+                    "{}",
+                    self.i18n
+                        .tr_args("doctor-fix-failure", Some(&i18n::arg("error", e.to_string())))
+                );
                 Ok(1)
             }
         }
     }
 }
+
+/// Split a shell-like command line into tokens, respecting single and
+/// double quotes so an alias expansion like `maya --ignore-cmd -- -proj
+/// "/shows/foo bar"` keeps its quoted argument intact. Unterminated quotes
+/// consume the rest of the string rather than erroring, since this only
+/// ever runs against config the user controls.
+fn tokenize_command_line(line: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut in_token = false;
+    let mut quote: Option<char> = None;
+
+    for c in line.chars() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => current.push(c),
+            None if c == '\'' || c == '"' => {
+                quote = Some(c);
+                in_token = true;
+            }
+            None if c.is_whitespace() => {
+                if in_token {
+                    tokens.push(std::mem::take(&mut current));
+                    in_token = false;
+                }
+            }
+            None => {
+                current.push(c);
+                in_token = true;
+            }
+        }
+    }
+
+    if in_token {
+        tokens.push(current);
+    }
+
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize_command_line_splits_on_whitespace() {
+        assert_eq!(
+            tokenize_command_line("maya --ignore-cmd -- -proj /shows/foo"),
+            vec!["maya", "--ignore-cmd", "--", "-proj", "/shows/foo"]
+        );
+    }
+
+    #[test]
+    fn test_tokenize_command_line_respects_quotes() {
+        assert_eq!(
+            tokenize_command_line(r#"maya -proj "/shows/foo bar" -x 'baz qux'"#),
+            vec!["maya", "-proj", "/shows/foo bar", "-x", "baz qux"]
+        );
+    }
+
+    #[test]
+    fn test_tokenize_command_line_empty() {
+        assert!(tokenize_command_line("   ").is_empty());
+    }
+
+    fn app_with_aliases(aliases: HashMap<String, AliasValue>) -> CliApp {
+        CliApp {
+            plugins: HashMap::new(),
+            aliases,
+            i18n: Localizer::detect(&[]),
+            command_timeouts: CommandTimeouts::default(),
+        }
+    }
+
+    #[test]
+    fn test_resolve_args_expands_list_alias_verbatim() {
+        let mut aliases = HashMap::new();
+        aliases.insert(
+            "maya_batch".to_string(),
+            AliasValue::List(vec!["maya".to_string(), "--batch".to_string(), "-q".to_string()]),
+        );
+        let app = app_with_aliases(aliases);
+
+        let resolved = app
+            .resolve_args(vec!["rt".to_string(), "maya_batch".to_string()])
+            .unwrap();
+
+        assert_eq!(resolved, vec!["rt", "maya", "--batch", "-q"]);
+    }
+
+    #[test]
+    fn test_resolve_args_expands_alias_of_alias() {
+        let mut aliases = HashMap::new();
+        aliases.insert(
+            "mb".to_string(),
+            AliasValue::String("maya_batch".to_string()),
+        );
+        aliases.insert(
+            "maya_batch".to_string(),
+            AliasValue::List(vec!["maya".to_string(), "--batch".to_string()]),
+        );
+        let app = app_with_aliases(aliases);
+
+        let resolved = app
+            .resolve_args(vec!["rt".to_string(), "mb".to_string()])
+            .unwrap();
+
+        assert_eq!(resolved, vec!["rt", "maya", "--batch"]);
+    }
+
+    #[test]
+    fn test_resolve_args_rejects_alias_cycle() {
+        let mut aliases = HashMap::new();
+        aliases.insert("a".to_string(), AliasValue::String("b".to_string()));
+        aliases.insert("b".to_string(), AliasValue::String("a".to_string()));
+        let app = app_with_aliases(aliases);
+
+        let err = app
+            .resolve_args(vec!["rt".to_string(), "a".to_string()])
+            .unwrap_err();
+
+        let message = err.to_string();
+        assert!(message.contains("Alias cycle detected"));
+        assert!(message.contains("a -> b -> a"));
+    }
+}
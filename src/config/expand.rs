@@ -0,0 +1,277 @@
+use crate::error::{Result, RezToolsError};
+use std::env;
+use std::path::Path;
+
+/// General path/string interpolation applied to every config `tool_path`
+/// (both the TOML and legacy-Python parsers) and, optionally, to plugin
+/// `command`/`requires` strings:
+///
+/// - a leading `~` expands to the home directory;
+/// - `${VAR}`, `$VAR`, and Windows `%VAR%` expand from the process
+///   environment;
+/// - the legacy `reztoolsconfig.py` snippets `os.path.expanduser(...)` and
+///   `os.path.join(os.path.dirname(__file__), ...)` are unwrapped, with
+///   `__file__` resolved against `context_dir` (the directory of the file
+///   `raw` came from, when known).
+///
+/// An environment variable referenced but not set is reported as a
+/// `ConfigError` naming it, rather than silently producing a broken path —
+/// mirroring how Mercurial's config layer resolves environment references.
+pub fn expand(raw: &str, context_dir: Option<&Path>) -> Result<String> {
+    let value = unwrap_legacy_python_expr(raw.trim(), context_dir)?;
+    let value = expand_home(&value)?;
+    expand_env_vars(&value)
+}
+
+/// Unwrap the handful of legacy Python snippet shapes that show up in
+/// `reztoolsconfig.py` `tool_paths` entries down to the plain path
+/// expression they describe. Anything that isn't one of these shapes is
+/// returned unchanged (a plain path, or a quoted string already stripped of
+/// its quotes by the caller).
+fn unwrap_legacy_python_expr(value: &str, context_dir: Option<&Path>) -> Result<String> {
+    if let Some(args) = strip_call(value, "os.path.join") {
+        let parts = split_top_level_commas(args)
+            .into_iter()
+            .map(|arg| unwrap_legacy_python_expr(arg, context_dir))
+            .collect::<Result<Vec<_>>>()?;
+        return Ok(parts.join(&std::path::MAIN_SEPARATOR.to_string()));
+    }
+
+    if let Some(inner) = strip_call(value, "os.path.expanduser") {
+        return unwrap_legacy_python_expr(inner, context_dir);
+    }
+
+    if let Some(inner) = strip_call(value, "os.path.dirname") {
+        if inner.trim() == "__file__" {
+            let dir = context_dir
+                .map(|p| p.to_string_lossy().to_string())
+                .unwrap_or_else(|| ".".to_string());
+            return Ok(dir);
+        }
+        return Ok(value.to_string());
+    }
+
+    Ok(strip_quotes(value).to_string())
+}
+
+/// If `value` is a call to `func_name(...)`, return the raw (unparsed)
+/// argument list inside the parens.
+fn strip_call<'a>(value: &'a str, func_name: &str) -> Option<&'a str> {
+    let rest = value.strip_prefix(func_name)?;
+    let rest = rest.trim_start();
+    let inner = rest.strip_prefix('(')?.strip_suffix(')')?;
+    Some(inner)
+}
+
+/// Split a Python call's argument list on top-level commas, ignoring commas
+/// nested inside quotes or parens, and trim whitespace from each argument.
+fn split_top_level_commas(args: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut quote: Option<char> = None;
+    let mut start = 0;
+
+    for (i, c) in args.char_indices() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None => match c {
+                '"' | '\'' => quote = Some(c),
+                '(' => depth += 1,
+                ')' => depth -= 1,
+                ',' if depth == 0 => {
+                    parts.push(args[start..i].trim());
+                    start = i + 1;
+                }
+                _ => {}
+            },
+        }
+    }
+    parts.push(args[start..].trim());
+    parts
+}
+
+/// Strip a single matching pair of leading/trailing quotes, if present.
+fn strip_quotes(value: &str) -> &str {
+    for quote in ['"', '\''] {
+        if let Some(inner) = value
+            .strip_prefix(quote)
+            .and_then(|v| v.strip_suffix(quote))
+        {
+            return inner;
+        }
+    }
+    value
+}
+
+/// Expand a leading `~` (or `~/...`, `~\...`) to the user's home directory.
+fn expand_home(value: &str) -> Result<String> {
+    let Some(rest) = value.strip_prefix('~') else {
+        return Ok(value.to_string());
+    };
+    if !rest.is_empty() && !rest.starts_with('/') && !rest.starts_with('\\') {
+        // Not `~` alone or `~/...` (e.g. `~foo`) - leave untouched
+        return Ok(value.to_string());
+    }
+
+    let home = dirs::home_dir().ok_or_else(|| {
+        RezToolsError::ConfigError(
+            "Could not determine home directory to expand '~' in tool_paths".to_string(),
+        )
+    })?;
+    let rest = rest.trim_start_matches(['/', '\\']);
+    Ok(home.join(rest).to_string_lossy().to_string())
+}
+
+/// Expand `${VAR}`, `$VAR`, and Windows `%VAR%` references against the
+/// process environment. A reference to a variable that isn't set is a hard
+/// error rather than being left in place or silently dropped.
+fn expand_env_vars(value: &str) -> Result<String> {
+    let chars: Vec<char> = value.chars().collect();
+    let mut result = String::with_capacity(value.len());
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if c == '$' && chars.get(i + 1) == Some(&'{') {
+            let Some(rel_end) = chars[i + 2..].iter().position(|&c| c == '}') else {
+                return Err(RezToolsError::ConfigError(format!(
+                    "Unterminated '${{' in tool_path expression: {}",
+                    value
+                )));
+            };
+            let name: String = chars[i + 2..i + 2 + rel_end].iter().collect();
+            result.push_str(&lookup_env(&name)?);
+            i += 2 + rel_end + 1;
+        } else if c == '$' && chars.get(i + 1).is_some_and(|&c| is_env_var_char(c)) {
+            let start = i + 1;
+            let mut end = start;
+            while end < chars.len() && is_env_var_char(chars[end]) {
+                end += 1;
+            }
+            let name: String = chars[start..end].iter().collect();
+            result.push_str(&lookup_env(&name)?);
+            i = end;
+        } else if c == '%' {
+            if let Some(rel_end) = chars[i + 1..].iter().position(|&c| c == '%') {
+                let name: String = chars[i + 1..i + 1 + rel_end].iter().collect();
+                if !name.is_empty() && name.chars().all(is_env_var_char) {
+                    result.push_str(&lookup_env(&name)?);
+                    i += 1 + rel_end + 1;
+                    continue;
+                }
+            }
+            result.push(c);
+            i += 1;
+        } else {
+            result.push(c);
+            i += 1;
+        }
+    }
+
+    Ok(result)
+}
+
+fn is_env_var_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+fn lookup_env(name: &str) -> Result<String> {
+    env::var(name).map_err(|_| {
+        RezToolsError::ConfigError(format!(
+            "Environment variable '{}' referenced in config is not set",
+            name
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_expand_plain_path_is_unchanged() {
+        assert_eq!(expand("/opt/tools", None).unwrap(), "/opt/tools");
+    }
+
+    #[test]
+    fn test_expand_home_tilde() {
+        let home = dirs::home_dir().unwrap();
+        assert_eq!(
+            expand("~/packages", None).unwrap(),
+            home.join("packages").to_string_lossy()
+        );
+    }
+
+    #[test]
+    fn test_expand_tilde_user_is_left_alone() {
+        // `~foo` is a different user's home in shell semantics; we only
+        // expand a lone `~` or `~/...`/`~\...`
+        assert_eq!(expand("~foo/bar", None).unwrap(), "~foo/bar");
+    }
+
+    #[test]
+    fn test_expand_env_var_braced_and_bare() {
+        std::env::set_var("REZ_TOOLS_TEST_EXPAND_VAR", "/custom/tools");
+        assert_eq!(
+            expand("${REZ_TOOLS_TEST_EXPAND_VAR}/bin", None).unwrap(),
+            "/custom/tools/bin"
+        );
+        assert_eq!(
+            expand("$REZ_TOOLS_TEST_EXPAND_VAR/bin", None).unwrap(),
+            "/custom/tools/bin"
+        );
+        std::env::remove_var("REZ_TOOLS_TEST_EXPAND_VAR");
+    }
+
+    #[test]
+    fn test_expand_windows_percent_var() {
+        std::env::set_var("REZ_TOOLS_TEST_PERCENT_VAR", "C:\\tools");
+        assert_eq!(
+            expand("%REZ_TOOLS_TEST_PERCENT_VAR%\\bin", None).unwrap(),
+            "C:\\tools\\bin"
+        );
+        std::env::remove_var("REZ_TOOLS_TEST_PERCENT_VAR");
+    }
+
+    #[test]
+    fn test_expand_unset_env_var_is_a_config_error() {
+        let err = expand("$REZ_TOOLS_TEST_DEFINITELY_UNSET", None).unwrap_err();
+        assert!(matches!(err, RezToolsError::ConfigError(_)));
+        assert!(err
+            .to_string()
+            .contains("REZ_TOOLS_TEST_DEFINITELY_UNSET"));
+    }
+
+    #[test]
+    fn test_expand_legacy_expanduser_snippet() {
+        let home = dirs::home_dir().unwrap();
+        assert_eq!(
+            expand(r#"os.path.expanduser("~/packages")"#, None).unwrap(),
+            home.join("packages").to_string_lossy()
+        );
+    }
+
+    #[test]
+    fn test_expand_legacy_dirname_file_snippet() {
+        let context_dir = PathBuf::from("/configs/project");
+        assert_eq!(
+            expand(
+                r#"os.path.join(os.path.dirname(__file__), "tools")"#,
+                Some(&context_dir)
+            )
+            .unwrap(),
+            format!("/configs/project{}tools", std::path::MAIN_SEPARATOR)
+        );
+    }
+
+    #[test]
+    fn test_expand_dirname_file_without_context_dir_falls_back_to_dot() {
+        assert_eq!(
+            expand(r#"os.path.dirname(__file__)"#, None).unwrap(),
+            "."
+        );
+    }
+}
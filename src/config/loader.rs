@@ -1,30 +1,80 @@
-use crate::config::Config;
+use crate::config::{expand, AliasValue, Config, ConfigOrigin};
 use crate::error::{Result, RezToolsError};
 use log::{debug, warn};
 use std::env;
 use std::fs;
 use std::path::{Path, PathBuf};
 
-/// Load configuration from environment variable or default locations
+/// Load configuration by folding every available layer together, lowest to
+/// highest precedence: built-in defaults, a system-wide config, the user's
+/// home-directory config, a project-local config in the current directory,
+/// then (highest) the file named by `REZ_TOOL_CONFIG`. A layer that doesn't
+/// exist is silently skipped; one that exists but fails to parse is a hard
+/// error, since the user clearly meant to use it.
 pub fn load_config() -> Result<Config> {
-    // Check for REZ_TOOL_CONFIG environment variable
-    if let Ok(config_path) = env::var("REZ_TOOL_CONFIG") {
-        debug!("Loading config from REZ_TOOL_CONFIG: {}", config_path);
-        return load_config_from_file(&config_path);
+    let mut config = Config::default();
+
+    for (path, origin) in optional_layers() {
+        if !path.exists() {
+            continue;
+        }
+        debug!("Loading config layer ({}): {}", origin, path.display());
+        let layer = load_config_from_file(&path)?;
+        config.merge(layer, origin);
+    }
+
+    if let Ok(env_path) = env::var("REZ_TOOL_CONFIG") {
+        let env_path = PathBuf::from(env_path);
+        debug!(
+            "Loading config layer (REZ_TOOL_CONFIG): {}",
+            env_path.display()
+        );
+        let layer = load_config_from_file(&env_path)?;
+        config.merge(layer, ConfigOrigin::EnvOverride(env_path));
+    } else {
+        debug!("REZ_TOOL_CONFIG is not set, skipping that layer");
+    }
+
+    Ok(config)
+}
+
+/// Candidate config layers below `REZ_TOOL_CONFIG`, in ascending precedence
+/// order. `REZ_TOOL_CONFIG` is handled separately by `load_config` since,
+/// unlike these, an explicitly-set-but-missing file is a hard error rather
+/// than something to skip.
+fn optional_layers() -> Vec<(PathBuf, ConfigOrigin)> {
+    let mut layers = Vec::new();
+
+    if let Some(system_path) = system_config_path() {
+        layers.push((system_path.clone(), ConfigOrigin::System(system_path)));
     }
 
-    // Check for default config file in home directory
     if let Some(home_dir) = dirs::home_dir() {
-        let default_config = home_dir.join("reztoolsconfig.py");
-        if default_config.exists() {
-            debug!("Loading config from default location: {}", default_config.display());
-            return load_config_from_file(&default_config);
+        let home_py = home_dir.join("reztoolsconfig.py");
+        if home_py.exists() {
+            layers.push((home_py.clone(), ConfigOrigin::Home(home_py)));
+        } else {
+            let home_toml = home_dir.join("reztoolsconfig.toml");
+            layers.push((home_toml.clone(), ConfigOrigin::Home(home_toml)));
         }
     }
 
-    // Return default configuration
-    debug!("No config file found, using default configuration");
-    Ok(Config::default())
+    if let Ok(cwd) = env::current_dir() {
+        let project_path = cwd.join("reztoolsconfig.toml");
+        layers.push((project_path.clone(), ConfigOrigin::Project(project_path)));
+    }
+
+    layers
+}
+
+/// The system-wide config file location, if this platform has one
+fn system_config_path() -> Option<PathBuf> {
+    if cfg!(target_os = "windows") {
+        env::var_os("PROGRAMDATA")
+            .map(|program_data| PathBuf::from(program_data).join("rez-tools").join("config.toml"))
+    } else {
+        Some(PathBuf::from("/etc/rez-tools/config.toml"))
+    }
 }
 
 /// Load configuration from a config file (Python or TOML)
@@ -39,16 +89,35 @@ fn load_config_from_file<P: AsRef<Path>>(config_path: P) -> Result<Config> {
     }
 
     let content = fs::read_to_string(config_path)?;
+    let extension = config_path
+        .extension()
+        .and_then(|s| s.to_str())
+        .unwrap_or("py");
 
-    // Determine file type by extension
-    if let Some(extension) = config_path.extension().and_then(|s| s.to_str()) {
-        match extension.to_lowercase().as_str() {
-            "toml" => parse_toml_config(&content),
-            "py" => parse_python_config(&content, Some(config_path)),
-            _ => parse_python_config(&content, Some(config_path)), // Default to Python
-        }
-    } else {
-        parse_python_config(&content, Some(config_path))
+    dispatch_config_content(&content, extension, Some(config_path))
+}
+
+/// Load configuration from in-memory `content`, dispatching on
+/// `extension_hint` ("toml" or "py") the same way [`load_config_from_file`]
+/// dispatches on a real file's extension. Lets an embedding application
+/// hand rez-tools a config that doesn't live on disk.
+pub fn load_config_from_str(content: &str, extension_hint: &str) -> Result<Config> {
+    dispatch_config_content(content, extension_hint, None)
+}
+
+/// Determine file type by extension (defaulting to Python) and parse
+/// accordingly. `config_path`, when present, lets the Python branch execute
+/// the file with a real interpreter instead of falling straight back to the
+/// simple parser.
+fn dispatch_config_content(
+    content: &str,
+    extension_hint: &str,
+    config_path: Option<&Path>,
+) -> Result<Config> {
+    match extension_hint.to_lowercase().as_str() {
+        "toml" => parse_toml_config(content, config_path),
+        "py" => parse_python_config(content, config_path),
+        _ => parse_python_config(content, config_path), // Default to Python
     }
 }
 
@@ -63,7 +132,7 @@ fn parse_python_config(content: &str, config_file_path: Option<&Path>) -> Result
     }
 
     // Fallback to simple parser
-    parse_python_config_simple(content)
+    parse_python_config_simple(content, config_file_path.and_then(Path::parent))
 }
 
 /// Execute Python config file and extract configuration
@@ -88,6 +157,8 @@ if 'tool_paths' in config_globals:
     result['tool_paths'] = config_globals['tool_paths']
 if 'extension' in config_globals:
     result['extension'] = config_globals['extension']
+if 'aliases' in config_globals:
+    result['aliases'] = config_globals['aliases']
 
 print(json.dumps(result))
 "#,
@@ -134,11 +205,13 @@ fn parse_json_config(json_str: &str) -> Result<Config> {
 
     let mut config = Config::default();
     config.tool_paths.clear();
+    config.tool_path_origins.clear();
 
     if let Some(tool_paths) = value.get("tool_paths").and_then(|v| v.as_array()) {
         for path in tool_paths {
             if let Some(path_str) = path.as_str() {
                 config.tool_paths.push(PathBuf::from(path_str));
+                config.tool_path_origins.push(ConfigOrigin::Default);
             }
         }
     }
@@ -147,6 +220,24 @@ fn parse_json_config(json_str: &str) -> Result<Config> {
         config.extension = extension.to_string();
     }
 
+    if let Some(aliases) = value.get("aliases").and_then(|v| v.as_object()) {
+        for (name, expansion) in aliases {
+            if let Some(expansion) = expansion.as_str() {
+                config
+                    .aliases
+                    .insert(name.clone(), AliasValue::String(expansion.to_string()));
+            } else if let Some(tokens) = expansion.as_array() {
+                let tokens: Vec<String> = tokens
+                    .iter()
+                    .filter_map(|token| token.as_str().map(String::from))
+                    .collect();
+                if !tokens.is_empty() {
+                    config.aliases.insert(name.clone(), AliasValue::List(tokens));
+                }
+            }
+        }
+    }
+
     // If no tool_paths were found, use default
     if config.tool_paths.is_empty() {
         config = Config::default();
@@ -156,15 +247,25 @@ fn parse_json_config(json_str: &str) -> Result<Config> {
 }
 
 /// Parse TOML configuration file
-fn parse_toml_config(content: &str) -> Result<Config> {
-    let config: Config = toml::from_str(content)
+fn parse_toml_config(content: &str, config_path: Option<&Path>) -> Result<Config> {
+    let mut config: Config = toml::from_str(content)
         .map_err(|e| RezToolsError::ConfigError(format!("Invalid TOML config: {}", e)))?;
+    // `tool_path_origins` is `#[serde(skip)]`, so fill it back in to stay
+    // parallel with the `tool_paths` just deserialized from this file.
+    config.tool_path_origins = vec![ConfigOrigin::Default; config.tool_paths.len()];
+
+    let config_dir = config_path.and_then(Path::parent);
+    config.tool_paths = config
+        .tool_paths
+        .into_iter()
+        .map(|path| expand::expand(&path.to_string_lossy(), config_dir).map(PathBuf::from))
+        .collect::<Result<Vec<_>>>()?;
 
     Ok(config)
 }
 
 /// Simple parser for basic Python config syntax (fallback)
-fn parse_python_config_simple(content: &str) -> Result<Config> {
+fn parse_python_config_simple(content: &str, config_dir: Option<&Path>) -> Result<Config> {
     let mut config = Config::default();
     config.tool_paths.clear(); // Clear default paths
 
@@ -186,7 +287,7 @@ fn parse_python_config_simple(content: &str) -> Result<Config> {
             if line.contains('[') && line.contains(']') {
                 // Single line list
                 let list_content = extract_list_content(line)?;
-                config.tool_paths = parse_path_list(&list_content)?;
+                config.tool_paths = parse_path_list(&list_content, config_dir)?;
                 in_tool_paths = false;
             } else if line.contains('[') {
                 bracket_count = 1;
@@ -209,7 +310,9 @@ fn parse_python_config_simple(content: &str) -> Result<Config> {
 
             // Extract path from this line
             if let Some(path) = extract_path_from_line(line) {
-                config.tool_paths.push(PathBuf::from(expand_path(&path)));
+                config
+                    .tool_paths
+                    .push(PathBuf::from(expand::expand(&path, config_dir)?));
             }
 
             if bracket_count == 0 {
@@ -222,6 +325,8 @@ fn parse_python_config_simple(content: &str) -> Result<Config> {
     if config.tool_paths.is_empty() {
         warn!("No tool_paths found in config, using default");
         config = Config::default();
+    } else {
+        config.tool_path_origins = vec![ConfigOrigin::Default; config.tool_paths.len()];
     }
 
     Ok(config)
@@ -240,13 +345,13 @@ fn extract_list_content(line: &str) -> Result<String> {
 }
 
 /// Parse a comma-separated list of paths
-fn parse_path_list(content: &str) -> Result<Vec<PathBuf>> {
+fn parse_path_list(content: &str, config_dir: Option<&Path>) -> Result<Vec<PathBuf>> {
     let mut paths = Vec::new();
 
     for item in content.split(',') {
         let item = item.trim();
         if let Some(path) = extract_string_value(item) {
-            paths.push(PathBuf::from(expand_path(&path)));
+            paths.push(PathBuf::from(expand::expand(&path, config_dir)?));
         }
     }
 
@@ -303,33 +408,6 @@ fn extract_path_from_line(line: &str) -> Option<String> {
     extract_string_value(line)
 }
 
-/// Expand path expressions like os.path.expanduser("~/packages")
-fn expand_path(path: &str) -> String {
-    // Handle os.path.expanduser("~/...")
-    if path.contains("expanduser") {
-        if let Some(start) = path.find('"') {
-            if let Some(end) = path.rfind('"') {
-                let inner_path = &path[start + 1..end];
-                if inner_path.starts_with("~/") {
-                    if let Some(home) = dirs::home_dir() {
-                        return home.join(&inner_path[2..]).to_string_lossy().to_string();
-                    }
-                }
-                return inner_path.to_string();
-            }
-        }
-    }
-
-    // Handle os.path.dirname(__file__)
-    if path.contains("dirname(__file__)") {
-        // This would need the actual file path context
-        // For now, return current directory
-        return ".".to_string();
-    }
-
-    path.to_string()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -358,4 +436,75 @@ extension = ".rt"
         assert_eq!(extract_string_value("'world'"), Some("world".to_string()));
         assert_eq!(extract_string_value("extension = \".rt\""), Some(".rt".to_string()));
     }
+
+    #[test]
+    fn test_load_config_from_str_dispatches_by_extension_hint() {
+        let config = load_config_from_str(r#"extension = ".tool""#, "toml").unwrap();
+        assert_eq!(config.extension, ".tool");
+
+        let config = load_config_from_str(
+            r#"
+tool_paths = [
+    "/path/to/tools"
+]
+"#,
+            "py",
+        )
+        .unwrap();
+        assert_eq!(config.tool_paths, vec![PathBuf::from("/path/to/tools")]);
+    }
+
+    #[test]
+    fn test_parse_toml_config_partial_falls_back_to_defaults() {
+        // A layer only needs to set what it overrides; every other field
+        // takes its default, except that `tool_paths` starts empty rather
+        // than with `Config::default()`'s path.
+        let config = parse_toml_config(r#"extension = ".tool""#, None).unwrap();
+        assert_eq!(config.extension, ".tool");
+        assert!(config.tool_paths.is_empty());
+        assert_eq!(config.tool_path_origins.len(), config.tool_paths.len());
+    }
+
+    #[test]
+    fn test_parse_toml_config_expands_tool_paths() {
+        std::env::set_var("REZ_TOOLS_TEST_LOADER_VAR", "/from/env");
+        let config =
+            parse_toml_config(r#"tool_paths = ["${REZ_TOOLS_TEST_LOADER_VAR}/tools"]"#, None)
+                .unwrap();
+        assert_eq!(config.tool_paths, vec![PathBuf::from("/from/env/tools")]);
+        std::env::remove_var("REZ_TOOLS_TEST_LOADER_VAR");
+    }
+
+    #[test]
+    fn test_load_config_from_file_resolves_dirname_file_against_real_path() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("reztoolsconfig.py");
+        std::fs::write(
+            &config_path,
+            r#"
+tool_paths = [
+    os.path.join(os.path.dirname(__file__), "tools"),
+]
+"#,
+        )
+        .unwrap();
+
+        let config = load_config_from_file(&config_path).unwrap();
+        assert_eq!(
+            config.tool_paths,
+            vec![temp_dir.path().join("tools")]
+        );
+    }
+
+    #[test]
+    fn test_parse_python_config_simple_errors_on_unset_env_var() {
+        let config_content = r#"
+tool_paths = [
+    "$REZ_TOOLS_TEST_LOADER_UNSET/tools",
+]
+"#;
+        let err = parse_python_config_simple(config_content, None).unwrap_err();
+        assert!(matches!(err, RezToolsError::ConfigError(_)));
+        assert!(err.to_string().contains("REZ_TOOLS_TEST_LOADER_UNSET"));
+    }
 }
@@ -1,26 +1,148 @@
+pub mod expand;
 pub mod loader;
 
+use crate::platform::extract::ExtractionLimits;
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
 
 /// Configuration for rez-tools
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
     /// Paths to search for .rt files
+    #[serde(default)]
     pub tool_paths: Vec<PathBuf>,
+    /// The config layer that introduced each entry of `tool_paths`, in the
+    /// same order, for error messages that need to point at the file a path
+    /// came from. Not itself part of any config file.
+    #[serde(skip)]
+    pub tool_path_origins: Vec<ConfigOrigin>,
     /// File extension for tool files (default: ".rt")
+    #[serde(default = "default_extension")]
     pub extension: String,
+    /// Maximum total uncompressed bytes an archive extraction may write
+    #[serde(default = "default_max_extraction_total_bytes")]
+    pub max_extraction_total_bytes: u64,
+    /// Maximum uncompressed bytes for any single archive entry
+    #[serde(default = "default_max_extraction_entry_bytes")]
+    pub max_extraction_entry_bytes: u64,
+    /// Maximum number of entries an archive extraction may contain
+    #[serde(default = "default_max_extraction_entry_count")]
+    pub max_extraction_entry_count: u64,
+    /// Short names that expand to a full plugin command line, e.g.
+    /// `maya2024 = "maya --ignore-cmd -- -proj /shows/foo"` or
+    /// `maya_batch = ["maya", "--batch", "-q"]`, cargo `[alias]`-style
+    #[serde(default)]
+    pub aliases: HashMap<String, AliasValue>,
+    /// Timeout, in seconds, for external commands that install software
+    /// (`pip install`, `uv venv`, `uv tool install`, ...). Overridable via
+    /// the `RT_INSTALL_TIMEOUT_SECS` environment variable.
+    #[serde(default = "default_install_command_timeout_secs")]
+    pub install_command_timeout_secs: u64,
+    /// Timeout, in seconds, for quick external probes (`uv --version`,
+    /// `pip show`, ...). Overridable via the `RT_PROBE_TIMEOUT_SECS`
+    /// environment variable.
+    #[serde(default = "default_probe_command_timeout_secs")]
+    pub probe_command_timeout_secs: u64,
+}
+
+/// A single alias expansion: either a shell-style string, split on
+/// whitespace (respecting quoting) at use, or an explicit list of argument
+/// tokens. Mirrors cargo's `alias.<name>` config, which accepts either form.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum AliasValue {
+    String(String),
+    List(Vec<String>),
+}
+
+/// Which configuration layer contributed a value, in ascending precedence
+/// order. Mirrors how Mercurial's `rhg` attributes merged config values back
+/// to the file that set them, so errors about a missing or colliding
+/// `tool_paths` entry can say which config file introduced it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigOrigin {
+    /// The built-in default configuration; no file on disk
+    Default,
+    /// A system-wide config file
+    System(PathBuf),
+    /// The user's home-directory config file
+    Home(PathBuf),
+    /// A project-local config file in the current directory
+    Project(PathBuf),
+    /// The file pointed to by the `REZ_TOOL_CONFIG` environment variable
+    EnvOverride(PathBuf),
+}
+
+impl ConfigOrigin {
+    /// The file this layer was loaded from, if any
+    pub fn path(&self) -> Option<&Path> {
+        match self {
+            ConfigOrigin::Default => None,
+            ConfigOrigin::System(path)
+            | ConfigOrigin::Home(path)
+            | ConfigOrigin::Project(path)
+            | ConfigOrigin::EnvOverride(path) => Some(path),
+        }
+    }
+}
+
+impl fmt::Display for ConfigOrigin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigOrigin::Default => write!(f, "built-in defaults"),
+            ConfigOrigin::System(path) => write!(f, "system config ({})", path.display()),
+            ConfigOrigin::Home(path) => write!(f, "home config ({})", path.display()),
+            ConfigOrigin::Project(path) => write!(f, "project config ({})", path.display()),
+            ConfigOrigin::EnvOverride(path) => {
+                write!(f, "REZ_TOOL_CONFIG ({})", path.display())
+            }
+        }
+    }
+}
+
+fn default_extension() -> String {
+    ".rt".to_string()
+}
+
+fn default_install_command_timeout_secs() -> u64 {
+    300
+}
+
+fn default_probe_command_timeout_secs() -> u64 {
+    10
+}
+
+fn default_max_extraction_total_bytes() -> u64 {
+    ExtractionLimits::default().max_total_bytes
+}
+
+fn default_max_extraction_entry_bytes() -> u64 {
+    ExtractionLimits::default().max_entry_bytes
+}
+
+fn default_max_extraction_entry_count() -> u64 {
+    ExtractionLimits::default().max_entry_count
 }
 
 impl Default for Config {
     fn default() -> Self {
+        let limits = ExtractionLimits::default();
         Self {
             tool_paths: vec![
                 dirs::home_dir()
                     .unwrap_or_else(|| PathBuf::from("."))
                     .join("packages"),
             ],
-            extension: ".rt".to_string(),
+            tool_path_origins: vec![ConfigOrigin::Default],
+            extension: default_extension(),
+            max_extraction_total_bytes: limits.max_total_bytes,
+            max_extraction_entry_bytes: limits.max_entry_bytes,
+            max_extraction_entry_count: limits.max_entry_count,
+            aliases: HashMap::new(),
+            install_command_timeout_secs: default_install_command_timeout_secs(),
+            probe_command_timeout_secs: default_probe_command_timeout_secs(),
         }
     }
 }
@@ -34,6 +156,36 @@ impl Config {
     /// Add a tool path to the configuration
     pub fn add_tool_path<P: Into<PathBuf>>(&mut self, path: P) {
         self.tool_paths.push(path.into());
+        self.tool_path_origins.push(ConfigOrigin::Default);
+    }
+
+    /// Fold `other`, a config layer loaded from `origin`, into `self`.
+    ///
+    /// Scalar fields (`extension`, the extraction limits, the command
+    /// timeouts) are overridden by `other`, since layers are folded in
+    /// ascending precedence order. `aliases` are merged key-by-key, with
+    /// `other`'s entries winning on a name collision. `tool_paths` are
+    /// concatenated and de-duplicated, preserving first-seen order, with
+    /// each newly-added path recorded against `origin` in
+    /// `tool_path_origins` so later errors can say which file introduced it.
+    pub fn merge(&mut self, other: Config, origin: ConfigOrigin) {
+        self.extension = other.extension;
+        self.max_extraction_total_bytes = other.max_extraction_total_bytes;
+        self.max_extraction_entry_bytes = other.max_extraction_entry_bytes;
+        self.max_extraction_entry_count = other.max_extraction_entry_count;
+        self.install_command_timeout_secs = other.install_command_timeout_secs;
+        self.probe_command_timeout_secs = other.probe_command_timeout_secs;
+
+        for (name, value) in other.aliases {
+            self.aliases.insert(name, value);
+        }
+
+        for path in other.tool_paths {
+            if !self.tool_paths.contains(&path) {
+                self.tool_paths.push(path);
+                self.tool_path_origins.push(origin.clone());
+            }
+        }
     }
 
     /// Set the file extension
@@ -41,6 +193,22 @@ impl Config {
         self.extension = extension.into();
     }
 
+    /// Build the `ExtractionLimits` archive extraction should enforce, based
+    /// on this configuration
+    pub fn extraction_limits(&self) -> ExtractionLimits {
+        ExtractionLimits {
+            max_total_bytes: self.max_extraction_total_bytes,
+            max_entry_bytes: self.max_extraction_entry_bytes,
+            max_entry_count: self.max_extraction_entry_count,
+        }
+    }
+
+    /// Build the `CommandTimeouts` external command invocations should use,
+    /// based on this configuration
+    pub fn command_timeouts(&self) -> crate::platform::timeout::CommandTimeouts {
+        crate::platform::timeout::CommandTimeouts::from_config(self)
+    }
+
     /// Expand and normalize all tool paths
     pub fn normalize_paths(&mut self) {
         self.tool_paths = self
@@ -65,3 +233,62 @@ impl Config {
             .collect();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_overrides_scalars_with_higher_layer() {
+        let mut config = Config::default();
+        let mut layer = Config::default();
+        layer.tool_paths.clear();
+        layer.tool_path_origins.clear();
+        layer.extension = ".tool".to_string();
+
+        config.merge(layer, ConfigOrigin::Project(PathBuf::from("./reztoolsconfig.toml")));
+
+        assert_eq!(config.extension, ".tool");
+    }
+
+    #[test]
+    fn test_merge_concatenates_and_dedupes_tool_paths() {
+        let mut config = Config::default();
+        let base_path = config.tool_paths[0].clone();
+
+        let mut layer = Config::default();
+        layer.tool_paths = vec![base_path.clone(), PathBuf::from("/studio/packages")];
+        layer.tool_path_origins.clear();
+
+        let origin = ConfigOrigin::Home(PathBuf::from("~/reztoolsconfig.toml"));
+        config.merge(layer, origin.clone());
+
+        assert_eq!(
+            config.tool_paths,
+            vec![base_path, PathBuf::from("/studio/packages")]
+        );
+        assert_eq!(config.tool_path_origins, vec![ConfigOrigin::Default, origin]);
+    }
+
+    #[test]
+    fn test_merge_lets_later_alias_win_on_name_collision() {
+        let mut config = Config::default();
+        config
+            .aliases
+            .insert("mb".to_string(), AliasValue::String("old".to_string()));
+
+        let mut layer = Config::default();
+        layer.tool_paths.clear();
+        layer.tool_path_origins.clear();
+        layer
+            .aliases
+            .insert("mb".to_string(), AliasValue::String("new".to_string()));
+
+        config.merge(layer, ConfigOrigin::EnvOverride(PathBuf::from("/tmp/config.toml")));
+
+        assert!(matches!(
+            config.aliases.get("mb"),
+            Some(AliasValue::String(expansion)) if expansion == "new"
+        ));
+    }
+}
@@ -17,6 +17,12 @@ pub enum RezToolsError {
     YamlError(serde_yaml::Error),
     /// Regex error
     RegexError(regex::Error),
+    /// Archive extraction refused an unsafe or oversized entry
+    ExtractionError(String),
+    /// Self-update check or install failed
+    UpdateError(String),
+    /// An external command was killed after exceeding its configured timeout
+    TimeoutError(String),
 }
 
 impl fmt::Display for RezToolsError {
@@ -29,6 +35,9 @@ impl fmt::Display for RezToolsError {
             RezToolsError::IoError(err) => write!(f, "IO error: {}", err),
             RezToolsError::YamlError(err) => write!(f, "YAML error: {}", err),
             RezToolsError::RegexError(err) => write!(f, "Regex error: {}", err),
+            RezToolsError::ExtractionError(msg) => write!(f, "Extraction error: {}", msg),
+            RezToolsError::UpdateError(msg) => write!(f, "Update error: {}", msg),
+            RezToolsError::TimeoutError(msg) => write!(f, "Timeout error: {}", msg),
         }
     }
 }
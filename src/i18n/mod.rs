@@ -0,0 +1,212 @@
+//! Fluent-based localization for `rt`'s CLI output, modeled on amethyst's
+//! `i18n` crate: translation bundles are embedded `.ftl` resources, the
+//! active locale is detected from the environment (with an explicit
+//! override), and a lookup that misses the active locale or a given key
+//! falls back to English rather than panicking or printing a blank line.
+
+use fluent_bundle::{FluentArgs, FluentBundle, FluentResource, FluentValue};
+use log::{debug, warn};
+use unic_langid::LanguageIdentifier;
+
+const EN_FTL: &str = include_str!("../../locales/en/rt.ftl");
+const JA_FTL: &str = include_str!("../../locales/ja/rt.ftl");
+
+/// Locale resources embedded in the binary, keyed by language subtag
+const LOCALES: &[(&str, &str)] = &[("en", EN_FTL), ("ja", JA_FTL)];
+
+/// Resolves message keys against the active locale's Fluent bundle,
+/// falling back to English when a key or the whole locale is unavailable
+pub struct Localizer {
+    locale: LanguageIdentifier,
+    bundle: Option<FluentBundle<FluentResource>>,
+    fallback: FluentBundle<FluentResource>,
+}
+
+impl Localizer {
+    /// Build a localizer for the locale requested via `--lang`, `RT_LANG`,
+    /// `LC_MESSAGES`, or `LANG` (checked in that order), defaulting to
+    /// English if none resolve to an embedded locale.
+    pub fn detect(args: &[String]) -> Self {
+        let locale = requested_locale(args);
+        Self::for_locale(locale)
+    }
+
+    fn for_locale(locale: LanguageIdentifier) -> Self {
+        let fallback = build_bundle("en", EN_FTL).expect("embedded en locale must parse");
+        let bundle = resource_for(&locale).and_then(|(name, src)| {
+            build_bundle(name, src)
+                .map_err(|e| warn!("Failed to parse locale '{}': {:?}", name, e))
+                .ok()
+        });
+
+        if bundle.is_none() && locale.language.as_str() != "en" {
+            debug!("No bundled locale for '{}', falling back to English", locale);
+        }
+        let locale = if bundle.is_some() {
+            locale
+        } else {
+            "en".parse().expect("'en' is a valid language identifier")
+        };
+
+        Self {
+            locale,
+            bundle,
+            fallback,
+        }
+    }
+
+    /// The resolved active locale (which may differ from what was
+    /// requested if no matching bundle was found)
+    pub fn locale(&self) -> &LanguageIdentifier {
+        &self.locale
+    }
+
+    /// Look up `key` with no arguments
+    pub fn tr(&self, key: &str) -> String {
+        self.tr_args(key, None)
+    }
+
+    /// Look up `key`, interpolating `args` into the message pattern.
+    /// Falls back to the English bundle if `key` is missing from the
+    /// active locale, and to the bare key if it's missing from both.
+    pub fn tr_args(&self, key: &str, args: Option<&FluentArgs>) -> String {
+        if let Some(bundle) = &self.bundle {
+            if let Some(message) = bundle.get_message(key).and_then(|m| m.value()) {
+                let mut errors = Vec::new();
+                return bundle.format_pattern(message, args, &mut errors).into_owned();
+            }
+        }
+
+        if let Some(message) = self.fallback.get_message(key).and_then(|m| m.value()) {
+            let mut errors = Vec::new();
+            return self.fallback.format_pattern(message, args, &mut errors).into_owned();
+        }
+
+        warn!("Missing translation for key '{}'", key);
+        key.to_string()
+    }
+}
+
+/// Build a single-argument `FluentArgs` map; a small convenience since
+/// nearly every interpolated message here only needs one placeholder
+pub fn arg(name: &'static str, value: impl Into<FluentValue<'static>>) -> FluentArgs<'static> {
+    let mut args = FluentArgs::new();
+    args.set(name, value);
+    args
+}
+
+fn build_bundle(
+    locale_name: &str,
+    source: &str,
+) -> Result<FluentBundle<FluentResource>, Vec<fluent_bundle::FluentError>> {
+    let langid: LanguageIdentifier = locale_name
+        .parse()
+        .unwrap_or_else(|_| "en".parse().expect("'en' is a valid language identifier"));
+    let resource = FluentResource::try_new(source.to_string())
+        .map_err(|(_, errors)| errors.into_iter().map(fluent_bundle::FluentError::from).collect())?;
+
+    let mut bundle = FluentBundle::new(vec![langid]);
+    bundle
+        .add_resource(resource)
+        .map_err(|errors| errors.into_iter().map(fluent_bundle::FluentError::from).collect())?;
+    Ok(bundle)
+}
+
+/// Find the embedded resource matching `locale`'s language subtag
+fn resource_for(locale: &LanguageIdentifier) -> Option<(&'static str, &'static str)> {
+    let language = locale.language.as_str();
+    LOCALES
+        .iter()
+        .find(|(name, _)| *name == language)
+        .copied()
+}
+
+/// Determine the requested locale: an explicit `--lang`/`--lang=<value>`
+/// argument, then `RT_LANG`, then `LC_MESSAGES`, then `LANG`, then `en`.
+fn requested_locale(args: &[String]) -> LanguageIdentifier {
+    let raw = lang_flag(args)
+        .or_else(|| std::env::var("RT_LANG").ok())
+        .or_else(|| std::env::var("LC_MESSAGES").ok())
+        .or_else(|| std::env::var("LANG").ok());
+
+    raw.as_deref()
+        .and_then(parse_locale)
+        .unwrap_or_else(|| "en".parse().expect("'en' is a valid language identifier"))
+}
+
+/// Extract `--lang <value>` or `--lang=<value>` from argv without
+/// involving clap, since the localizer must exist before argument parsing
+/// so even early errors can be localized
+fn lang_flag(args: &[String]) -> Option<String> {
+    let mut iter = args.iter();
+    while let Some(token) = iter.next() {
+        if let Some(value) = token.strip_prefix("--lang=") {
+            return Some(value.to_string());
+        }
+        if token == "--lang" {
+            return iter.next().cloned();
+        }
+    }
+    None
+}
+
+/// Parse a POSIX-style locale string (e.g. `en_US.UTF-8`, `ja_JP`) into a
+/// `LanguageIdentifier`, ignoring the encoding/modifier suffix
+fn parse_locale(raw: &str) -> Option<LanguageIdentifier> {
+    let base = raw.split(['.', '@']).next().unwrap_or(raw).replace('_', "-");
+    if base.is_empty() || base.eq_ignore_ascii_case("c") || base.eq_ignore_ascii_case("posix") {
+        return None;
+    }
+    base.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn falls_back_to_english_for_unknown_locale() {
+        let localizer = Localizer::for_locale("de".parse().unwrap());
+        assert_eq!(localizer.tr("list-no-plugins"), "No plugins found.");
+    }
+
+    #[test]
+    fn uses_japanese_bundle_when_available() {
+        let localizer = Localizer::for_locale("ja".parse().unwrap());
+        assert_eq!(localizer.tr("list-no-plugins"), "プラグインが見つかりません。");
+    }
+
+    #[test]
+    fn missing_key_falls_back_to_english_bundle() {
+        // "check-rez-error" has no Japanese translation, only English
+        let localizer = Localizer::for_locale("ja".parse().unwrap());
+        let message = localizer.tr_args("check-rez-error", Some(&arg("error", "boom")));
+        assert!(message.contains("boom"));
+    }
+
+    #[test]
+    fn unknown_key_returns_the_key_itself() {
+        let localizer = Localizer::for_locale("en".parse().unwrap());
+        assert_eq!(localizer.tr("does-not-exist"), "does-not-exist");
+    }
+
+    #[test]
+    fn lang_flag_overrides_env() {
+        assert_eq!(
+            lang_flag(&["rt".to_string(), "--lang".to_string(), "ja".to_string()]),
+            Some("ja".to_string())
+        );
+        assert_eq!(
+            lang_flag(&["rt".to_string(), "--lang=ja".to_string()]),
+            Some("ja".to_string())
+        );
+        assert_eq!(lang_flag(&["rt".to_string(), "list".to_string()]), None);
+    }
+
+    #[test]
+    fn parses_posix_style_locale_strings() {
+        assert_eq!(parse_locale("ja_JP.UTF-8").unwrap(), "ja-JP".parse().unwrap());
+        assert!(parse_locale("C").is_none());
+        assert!(parse_locale("POSIX").is_none());
+    }
+}
@@ -12,6 +12,7 @@
 //! - Generate dynamic command-line interfaces
 //! - Execute tools within rez environments
 //! - Support for both attached and detached execution modes
+//! - Fluent-based localization of CLI output, with English fallback
 //!
 //! ## Example
 //!
@@ -21,23 +22,59 @@
 //! #[tokio::main]
 //! async fn main() -> Result<(), Box<dyn std::error::Error>> {
 //!     let app = CliApp::new()?;
-//!     let exit_code = app.run().await?;
+//!     let exit_code = app.run(std::env::args().collect()).await?;
 //!     std::process::exit(exit_code);
 //! }
 //! ```
+//!
+//! Embedding rez-tools in a larger tool works the same way, but through
+//! [`run`], which takes its arguments and configuration explicitly and
+//! returns an [`std::process::ExitCode`] instead of calling
+//! `std::process::exit`:
+//!
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> rez_tools::Result<()> {
+//! use rez_tools::Config;
+//!
+//! let args = vec!["rt".to_string(), "list".to_string()];
+//! let config = Config::default();
+//! let exit_code = rez_tools::run(args, config).await?;
+//! # let _ = exit_code;
+//! # Ok(())
+//! # }
+//! ```
 
 pub mod cli;
 pub mod config;
 pub mod error;
+pub mod i18n;
 pub mod platform;
 pub mod plugin;
 pub mod rez;
+pub mod selfupdate;
 
 // Re-export commonly used types
 pub use config::Config;
 pub use error::{Result, RezToolsError};
 pub use plugin::Plugin;
 
+/// Run rez-tools against explicit `args` (as from `std::env::args()`) and an
+/// already-loaded `config`, returning a process exit status instead of
+/// calling `std::process::exit`. This is the embeddable counterpart to
+/// `CliApp::new().run(...)`: it skips the layered on-disk config discovery
+/// so a caller embedding rez-tools in a larger tool can supply its own
+/// configuration instead of shelling out to the `rt` binary.
+pub async fn run(args: Vec<String>, config: Config) -> Result<std::process::ExitCode> {
+    let app = cli::CliApp::with_config(config)?;
+    let code = app.run(args).await?;
+
+    Ok(match u8::try_from(code) {
+        Ok(code) => std::process::ExitCode::from(code),
+        Err(_) => std::process::ExitCode::FAILURE,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
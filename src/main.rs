@@ -15,7 +15,7 @@ async fn main() {
         }
     };
 
-    let exit_code = match app.run().await {
+    let exit_code = match app.run(std::env::args().collect()).await {
         Ok(code) => code,
         Err(e) => {
             eprintln!("Error: {}", e);
@@ -1,9 +1,11 @@
 use crate::error::{Result, RezToolsError};
 use crate::platform::RezEnvironment;
 use log::{debug, info, warn};
+use serde::Deserialize;
 use std::env;
+use std::io::Write;
 use std::path::PathBuf;
-use std::process::Command;
+use std::process::{Command, Stdio};
 
 /// Detect existing rez installation
 pub fn detect_rez_environment() -> Result<RezEnvironment> {
@@ -74,56 +76,85 @@ fn detect_rez_version() -> Result<String> {
     }
 }
 
-/// Get rez configuration
+/// Get rez configuration via a structured query rather than string slicing
 fn get_rez_config() -> Result<RezConfig> {
+    // Preferred path: rez supports dumping its resolved config as JSON directly
+    if let Ok(output) = Command::new("rez").args(&["config", "--json"]).output() {
+        if output.status.success() {
+            let json_str = String::from_utf8_lossy(&output.stdout);
+            if let Ok(config) = parse_rez_config_json(&json_str) {
+                return Ok(config);
+            }
+            debug!("Failed to parse `rez config --json` output, falling back");
+        }
+    }
+
+    // Fallback: pipe `rez config --print` (YAML-ish) through Python's yaml
+    // module to get a JSON document we can deserialize reliably.
     let output = Command::new("rez")
         .args(&["config", "--print"])
         .output()
         .map_err(|e| RezToolsError::ConfigError(format!("Failed to get rez config: {}", e)))?;
 
-    if output.status.success() {
-        let config_str = String::from_utf8_lossy(&output.stdout);
-        parse_rez_config(&config_str)
-    } else {
-        Err(RezToolsError::ConfigError("Failed to get rez config".to_string()))
+    if !output.status.success() {
+        return Err(RezToolsError::ConfigError(
+            "Failed to get rez config".to_string(),
+        ));
     }
+
+    convert_config_yaml_to_json(&output.stdout).and_then(|json_str| parse_rez_config_json(&json_str))
 }
 
-/// Parse rez configuration output
-fn parse_rez_config(config_str: &str) -> Result<RezConfig> {
-    let mut config = RezConfig {
-        packages_path: Vec::new(),
-        python_path: None,
-    };
+/// Run the YAML-ish `rez config --print` output through a small Python helper
+/// that parses it with PyYAML and re-emits it as JSON.
+fn convert_config_yaml_to_json(config_yaml: &[u8]) -> Result<String> {
+    let script = "import sys, json, yaml; json.dump(yaml.safe_load(sys.stdin), sys.stdout)";
+
+    for python_cmd in ["python3", "python"] {
+        let mut child = match Command::new(python_cmd)
+            .args(["-c", script])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+        {
+            Ok(child) => child,
+            Err(e) => {
+                debug!("Failed to run {}: {}", python_cmd, e);
+                continue;
+            }
+        };
+
+        if let Some(stdin) = child.stdin.as_mut() {
+            if stdin.write_all(config_yaml).is_err() {
+                continue;
+            }
+        }
 
-    for line in config_str.lines() {
-        let line = line.trim();
-
-        if line.starts_with("packages_path:") {
-            // Parse packages_path list
-            if let Some(paths_str) = line.strip_prefix("packages_path:") {
-                let paths_str = paths_str.trim();
-                if paths_str.starts_with('[') && paths_str.ends_with(']') {
-                    let paths_content = &paths_str[1..paths_str.len()-1];
-                    for path in paths_content.split(',') {
-                        let path = path.trim().trim_matches('"').trim_matches('\'');
-                        if !path.is_empty() {
-                            config.packages_path.push(PathBuf::from(path));
-                        }
-                    }
-                }
+        match child.wait_with_output() {
+            Ok(output) if output.status.success() => {
+                return Ok(String::from_utf8_lossy(&output.stdout).to_string());
             }
-        } else if line.starts_with("python_executable:") {
-            if let Some(python_str) = line.strip_prefix("python_executable:") {
-                let python_path = python_str.trim().trim_matches('"').trim_matches('\'');
-                if !python_path.is_empty() {
-                    config.python_path = Some(PathBuf::from(python_path));
-                }
+            Ok(output) => {
+                debug!(
+                    "{} failed to convert rez config to JSON: {}",
+                    python_cmd,
+                    String::from_utf8_lossy(&output.stderr)
+                );
             }
+            Err(e) => debug!("Failed to wait on {}: {}", python_cmd, e),
         }
     }
 
-    Ok(config)
+    Err(RezToolsError::ConfigError(
+        "No working Python interpreter found to parse rez config".to_string(),
+    ))
+}
+
+/// Deserialize a JSON document describing the rez config into `RezConfig`
+fn parse_rez_config_json(json_str: &str) -> Result<RezConfig> {
+    serde_json::from_str(json_str)
+        .map_err(|e| RezToolsError::ConfigError(format!("Invalid rez config JSON: {}", e)))
 }
 
 /// Detect our Python Build Standalone installation with rez
@@ -263,10 +294,19 @@ fn detect_python_build_standalone() -> Option<PathBuf> {
     None
 }
 
-#[derive(Debug)]
-struct RezConfig {
-    packages_path: Vec<PathBuf>,
-    python_path: Option<PathBuf>,
+/// Structured view of the fields we care about from `rez config`
+#[derive(Debug, Default, Deserialize)]
+pub struct RezConfig {
+    #[serde(default)]
+    pub packages_path: Vec<PathBuf>,
+    #[serde(default, rename = "python_executable")]
+    pub python_path: Option<PathBuf>,
+    #[serde(default)]
+    pub local_packages_path: Option<PathBuf>,
+    #[serde(default)]
+    pub release_packages_path: Vec<PathBuf>,
+    #[serde(default)]
+    pub plugin_path: Vec<PathBuf>,
 }
 
 #[cfg(test)]
@@ -282,37 +322,42 @@ mod tests {
     }
 
     #[test]
-    fn test_parse_rez_config() {
-        let config_str = r#"
-packages_path: ["/path/to/packages", "/another/path"]
-python_executable: "/usr/bin/python3"
-"#;
-        let config = parse_rez_config(config_str).unwrap();
+    fn test_parse_rez_config_json() {
+        let json_str = r#"{
+            "packages_path": ["/path/to/packages", "/another/path"],
+            "python_executable": "/usr/bin/python3",
+            "local_packages_path": "/home/user/packages",
+            "release_packages_path": ["/release/packages"],
+            "plugin_path": ["/rez/plugins"]
+        }"#;
+        let config = parse_rez_config_json(json_str).unwrap();
         assert_eq!(config.packages_path.len(), 2);
-        assert!(config.python_path.is_some());
         assert_eq!(config.packages_path[0], PathBuf::from("/path/to/packages"));
         assert_eq!(config.packages_path[1], PathBuf::from("/another/path"));
-        assert_eq!(config.python_path.unwrap(), PathBuf::from("/usr/bin/python3"));
+        assert_eq!(
+            config.python_path.unwrap(),
+            PathBuf::from("/usr/bin/python3")
+        );
+        assert_eq!(
+            config.local_packages_path.unwrap(),
+            PathBuf::from("/home/user/packages")
+        );
+        assert_eq!(config.release_packages_path.len(), 1);
+        assert_eq!(config.plugin_path.len(), 1);
     }
 
     #[test]
-    fn test_parse_rez_config_empty() {
-        let config_str = "";
-        let config = parse_rez_config(config_str).unwrap();
+    fn test_parse_rez_config_json_empty() {
+        let config = parse_rez_config_json("{}").unwrap();
         assert_eq!(config.packages_path.len(), 0);
         assert!(config.python_path.is_none());
+        assert!(config.local_packages_path.is_none());
     }
 
     #[test]
-    fn test_parse_rez_config_malformed() {
-        let config_str = r#"
-packages_path: ["/path/to/packages"
-python_executable:
-"#;
-        let config = parse_rez_config(config_str).unwrap();
-        // Should handle malformed config gracefully
-        assert_eq!(config.packages_path.len(), 0);
-        assert!(config.python_path.is_none());
+    fn test_parse_rez_config_json_malformed() {
+        let result = parse_rez_config_json("not json");
+        assert!(result.is_err());
     }
 
     #[test]
@@ -0,0 +1,189 @@
+use crate::error::Result;
+use crate::platform::{
+    installer, rez_path,
+    timeout::{run_with_timeout, CommandTimeouts},
+};
+use log::debug;
+use tokio::process::Command as AsyncCommand;
+
+/// Outcome of probing a single piece of the install toolchain
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProbeStatus {
+    /// The tool is present and working
+    Ok,
+    /// The tool could not be found at all
+    Missing,
+    /// The tool was found but failed to run correctly
+    Broken,
+}
+
+/// Result of probing one piece of the install toolchain: what was checked,
+/// whether it's usable, any captured stderr, and a human remediation hint
+#[derive(Debug, Clone)]
+pub struct Probe {
+    pub name: String,
+    pub status: ProbeStatus,
+    pub detail: Option<String>,
+    pub remediation: Option<String>,
+}
+
+impl Probe {
+    fn ok(name: &str, detail: impl Into<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            status: ProbeStatus::Ok,
+            detail: Some(detail.into()),
+            remediation: None,
+        }
+    }
+
+    fn missing(name: &str, remediation: impl Into<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            status: ProbeStatus::Missing,
+            detail: None,
+            remediation: Some(remediation.into()),
+        }
+    }
+
+    fn broken(name: &str, detail: impl Into<String>, remediation: impl Into<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            status: ProbeStatus::Broken,
+            detail: Some(detail.into()),
+            remediation: Some(remediation.into()),
+        }
+    }
+}
+
+/// Probe `uv --version`
+async fn probe_uv(timeouts: CommandTimeouts) -> Probe {
+    let mut cmd = AsyncCommand::new("uv");
+    cmd.arg("--version");
+    match run_with_timeout(cmd, timeouts.probe).await {
+        Ok(output) if output.status.success() => {
+            Probe::ok("uv", String::from_utf8_lossy(&output.stdout).trim().to_string())
+        }
+        Ok(output) => Probe::broken(
+            "uv",
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            "Reinstall uv: https://docs.astral.sh/uv/getting-started/installation/",
+        ),
+        Err(e) => Probe::missing(
+            "uv",
+            format!(
+                "Install uv (https://docs.astral.sh/uv/getting-started/installation/): {}",
+                e
+            ),
+        ),
+    }
+}
+
+/// Probe `pip --version`
+async fn probe_pip(timeouts: CommandTimeouts) -> Probe {
+    let mut cmd = AsyncCommand::new("pip");
+    cmd.arg("--version");
+    match run_with_timeout(cmd, timeouts.probe).await {
+        Ok(output) if output.status.success() => {
+            Probe::ok("pip", String::from_utf8_lossy(&output.stdout).trim().to_string())
+        }
+        Ok(output) => Probe::broken(
+            "pip",
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            "Reinstall pip: python -m ensurepip --upgrade",
+        ),
+        Err(e) => Probe::missing(
+            "pip",
+            format!("Install pip: python -m ensurepip --upgrade ({})", e),
+        ),
+    }
+}
+
+/// Probe a system Python interpreter (`python3`, falling back to `python`)
+async fn probe_system_python(timeouts: CommandTimeouts) -> Probe {
+    for name in ["python3", "python"] {
+        let mut cmd = AsyncCommand::new(name);
+        cmd.arg("--version");
+        match run_with_timeout(cmd, timeouts.probe).await {
+            Ok(output) if output.status.success() => {
+                let version = String::from_utf8_lossy(&output.stdout).trim().to_string();
+                let version = if version.is_empty() {
+                    // Some very old Pythons print the version to stderr
+                    String::from_utf8_lossy(&output.stderr).trim().to_string()
+                } else {
+                    version
+                };
+                return Probe::ok("system python", format!("{} ({})", version, name));
+            }
+            Ok(_) | Err(_) => continue,
+        }
+    }
+
+    Probe::missing(
+        "system python",
+        "Install Python 3, or use 'rt install-rez --standalone' to bundle one",
+    )
+}
+
+/// Probe whether `~/.rez-tools/bin` (where the rez wrapper script is
+/// installed) is on PATH
+fn probe_rez_tools_bin_on_path() -> Probe {
+    let bin_dir = installer::rez_tools_dir().join("bin");
+
+    let on_path = std::env::var_os("PATH")
+        .map(|path_var| std::env::split_paths(&path_var).any(|dir| dir == bin_dir))
+        .unwrap_or(false);
+
+    if on_path {
+        Probe::ok("~/.rez-tools/bin on PATH", bin_dir.display().to_string())
+    } else {
+        Probe::missing(
+            "~/.rez-tools/bin on PATH",
+            format!(
+                "Add {} to your PATH, e.g. export PATH=\"{}:$PATH\"",
+                bin_dir.display(),
+                bin_dir.display()
+            ),
+        )
+    }
+}
+
+/// Probe whether a working `rez` is reachable
+fn probe_rez_reachable() -> Probe {
+    match rez_path::get_rez_path() {
+        Ok(path) => Probe::ok("rez", path.display().to_string()),
+        Err(e) => Probe::missing(
+            "rez",
+            format!("Run 'rt install-rez' to install rez automatically ({})", e),
+        ),
+    }
+}
+
+/// Run every probe and return the results in the fixed order they're
+/// reported to the user
+pub async fn run_diagnostics(timeouts: CommandTimeouts) -> Vec<Probe> {
+    vec![
+        probe_uv(timeouts).await,
+        probe_pip(timeouts).await,
+        probe_system_python(timeouts).await,
+        probe_rez_tools_bin_on_path(),
+        probe_rez_reachable(),
+    ]
+}
+
+/// Attempt to bootstrap whatever `diagnostics` found missing: installs rez
+/// if unreachable. Probes with no automated remediation (a missing system
+/// Python or PATH entry) are left for the user to act on manually.
+pub async fn fix(diagnostics: &[Probe], timeouts: CommandTimeouts) -> Result<Vec<String>> {
+    let mut actions = Vec::new();
+
+    if let Some(rez_probe) = diagnostics.iter().find(|p| p.name == "rez") {
+        if rez_probe.status != ProbeStatus::Ok {
+            debug!("doctor --fix: rez not reachable, attempting install_rez()");
+            installer::install_rez(None, false, timeouts).await?;
+            actions.push("Installed rez via 'rt install-rez'".to_string());
+        }
+    }
+
+    Ok(actions)
+}
@@ -1,9 +1,49 @@
 use crate::error::{Result, RezToolsError};
 use log::{debug, info, warn};
+use sha2::{Digest, Sha256};
 use std::path::Path;
 use std::time::Duration;
 use tokio::fs;
-use tokio::io::AsyncWriteExt;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+/// Compute the SHA-256 digest of the file at `path` and compare it against
+/// `expected` (case-insensitive hex), without re-downloading anything. This
+/// is the standalone counterpart to `download_file_verified`'s inline
+/// streaming check, for archives that arrived some other way - e.g. reused
+/// from a local bootstrap directory or mirror instead of GitHub.
+pub async fn verify_checksum(path: &Path, expected: &str) -> Result<()> {
+    let mut file = fs::File::open(path).await.map_err(|e| {
+        RezToolsError::ConfigError(format!(
+            "Failed to open {} for checksum verification: {}",
+            path.display(),
+            e
+        ))
+    })?;
+
+    let mut hasher = Sha256::new();
+    let mut buffer = [0u8; 64 * 1024];
+    loop {
+        let read = file.read(&mut buffer).await.map_err(|e| {
+            RezToolsError::ConfigError(format!("Failed to read {}: {}", path.display(), e))
+        })?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+
+    let actual = format!("{:x}", hasher.finalize());
+    if actual.eq_ignore_ascii_case(expected) {
+        Ok(())
+    } else {
+        Err(RezToolsError::ConfigError(format!(
+            "Checksum mismatch for {}: expected {}, got {}",
+            path.display(),
+            expected,
+            actual
+        )))
+    }
+}
 
 /// Download client with retry logic and progress reporting
 pub struct DownloadClient {
@@ -75,9 +115,186 @@ impl DownloadClient {
         unreachable!()
     }
 
-    /// Single download attempt
+    /// Single download attempt, resuming from a partial `.tmp` file if one exists
     async fn try_download<P: AsRef<Path>>(&self, url: &str, destination: P) -> Result<()> {
         let destination = destination.as_ref();
+        let temp_path = destination.with_extension("tmp");
+
+        // Resume from wherever the previous attempt left off
+        let resume_from = match fs::metadata(&temp_path).await {
+            Ok(metadata) => metadata.len(),
+            Err(_) => 0,
+        };
+
+        let mut request = self.client.get(url).timeout(self.timeout);
+        if resume_from > 0 {
+            debug!(
+                "Resuming download of {} from byte {}",
+                url, resume_from
+            );
+            request = request.header(reqwest::header::RANGE, format!("bytes={}-", resume_from));
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| RezToolsError::ConfigError(format!("Request failed: {}", e)))?;
+
+        let status = response.status();
+
+        // Server has nothing more to send: the file we already have is complete
+        if status == reqwest::StatusCode::RANGE_NOT_SATISFIABLE {
+            debug!("Server reports range not satisfiable; treating download as complete");
+            fs::rename(&temp_path, destination)
+                .await
+                .map_err(|e| RezToolsError::ConfigError(format!("Failed to move file: {}", e)))?;
+            return Ok(());
+        }
+
+        if !status.is_success() {
+            return Err(RezToolsError::ConfigError(format!(
+                "HTTP error {}: {}",
+                status,
+                status.canonical_reason().unwrap_or("Unknown error")
+            )));
+        }
+
+        // The server may ignore the Range header (200 OK) or honor it (206 Partial Content)
+        let resuming = resume_from > 0 && status == reqwest::StatusCode::PARTIAL_CONTENT;
+        if resume_from > 0 && !resuming {
+            debug!("Server ignored range request; restarting download from scratch");
+        }
+
+        // Get content length for progress reporting
+        let total_size = response.content_length().map(|len| {
+            if resuming {
+                len + resume_from
+            } else {
+                len
+            }
+        });
+        if let Some(size) = total_size {
+            debug!("Download size: {} bytes", size);
+        }
+
+        // Open the temp file: append if resuming, otherwise start fresh
+        let mut file = if resuming {
+            fs::OpenOptions::new()
+                .append(true)
+                .open(&temp_path)
+                .await
+                .map_err(|e| RezToolsError::ConfigError(format!("Failed to open file: {}", e)))?
+        } else {
+            fs::File::create(&temp_path)
+                .await
+                .map_err(|e| RezToolsError::ConfigError(format!("Failed to create file: {}", e)))?
+        };
+
+        // Download with streaming
+        let mut downloaded = if resuming { resume_from } else { 0 };
+        let mut stream = response.bytes_stream();
+
+        use futures_util::StreamExt;
+        while let Some(chunk) = stream.next().await {
+            let chunk =
+                chunk.map_err(|e| RezToolsError::ConfigError(format!("Stream error: {}", e)))?;
+
+            file.write_all(&chunk)
+                .await
+                .map_err(|e| RezToolsError::ConfigError(format!("Write error: {}", e)))?;
+
+            downloaded += chunk.len() as u64;
+
+            // Log progress periodically
+            if let Some(total) = total_size {
+                let progress = (downloaded as f64 / total as f64) * 100.0;
+                if downloaded % (1024 * 1024) == 0 || downloaded == total {
+                    debug!(
+                        "Downloaded {:.1}% ({} / {} bytes)",
+                        progress, downloaded, total
+                    );
+                }
+            }
+        }
+
+        // Ensure all data is written
+        file.flush()
+            .await
+            .map_err(|e| RezToolsError::ConfigError(format!("Flush error: {}", e)))?;
+
+        drop(file);
+
+        // Move temp file to final destination
+        fs::rename(&temp_path, destination)
+            .await
+            .map_err(|e| RezToolsError::ConfigError(format!("Failed to move file: {}", e)))?;
+
+        info!(
+            "Downloaded {} bytes to {}",
+            downloaded,
+            destination.display()
+        );
+        Ok(())
+    }
+
+    /// Download a file with retry logic, verifying its SHA-256 digest
+    pub async fn download_file_verified<P: AsRef<Path>>(
+        &self,
+        url: &str,
+        destination: P,
+        expected_sha256: &str,
+    ) -> Result<()> {
+        let destination = destination.as_ref();
+
+        // Create parent directory if it doesn't exist
+        if let Some(parent) = destination.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+
+        for attempt in 1..=self.max_retries {
+            info!(
+                "Downloading {} (attempt {} of {}, verified)",
+                url, attempt, self.max_retries
+            );
+
+            match self
+                .try_download_verified(url, destination, expected_sha256)
+                .await
+            {
+                Ok(()) => {
+                    info!("Successfully downloaded to {}", destination.display());
+                    return Ok(());
+                }
+                Err(e) => {
+                    warn!("Download attempt {} failed: {}", attempt, e);
+
+                    if attempt == self.max_retries {
+                        return Err(RezToolsError::ConfigError(format!(
+                            "Failed to download {} after {} attempts: {}",
+                            url, self.max_retries, e
+                        )));
+                    }
+
+                    // Exponential backoff
+                    let delay = Duration::from_secs(2_u64.pow(attempt as u32 - 1));
+                    debug!("Waiting {:?} before retry", delay);
+                    tokio::time::sleep(delay).await;
+                }
+            }
+        }
+
+        unreachable!()
+    }
+
+    /// Single verified download attempt
+    async fn try_download_verified<P: AsRef<Path>>(
+        &self,
+        url: &str,
+        destination: P,
+        expected_sha256: &str,
+    ) -> Result<()> {
+        let destination = destination.as_ref();
+        let expected_sha256 = expected_sha256.to_lowercase();
 
         // Start the download
         let response = self
@@ -99,7 +316,6 @@ impl DownloadClient {
             )));
         }
 
-        // Get content length for progress reporting
         let total_size = response.content_length();
         if let Some(size) = total_size {
             debug!("Download size: {} bytes", size);
@@ -111,8 +327,9 @@ impl DownloadClient {
             .await
             .map_err(|e| RezToolsError::ConfigError(format!("Failed to create file: {}", e)))?;
 
-        // Download with streaming
+        // Download with streaming, hashing as we go
         let mut downloaded = 0u64;
+        let mut hasher = Sha256::new();
         let mut stream = response.bytes_stream();
 
         use futures_util::StreamExt;
@@ -123,10 +340,10 @@ impl DownloadClient {
             file.write_all(&chunk)
                 .await
                 .map_err(|e| RezToolsError::ConfigError(format!("Write error: {}", e)))?;
+            hasher.update(&chunk);
 
             downloaded += chunk.len() as u64;
 
-            // Log progress periodically
             if let Some(total) = total_size {
                 let progress = (downloaded as f64 / total as f64) * 100.0;
                 if downloaded % (1024 * 1024) == 0 || downloaded == total {
@@ -145,13 +362,23 @@ impl DownloadClient {
 
         drop(file);
 
+        let actual_sha256 = format!("{:x}", hasher.finalize());
+        if actual_sha256 != expected_sha256 {
+            // Corrupted download: discard the temp file and let the retry loop try again
+            let _ = fs::remove_file(&temp_path).await;
+            return Err(RezToolsError::ConfigError(format!(
+                "Checksum mismatch for {}: expected {}, got {}",
+                url, expected_sha256, actual_sha256
+            )));
+        }
+
         // Move temp file to final destination
         fs::rename(&temp_path, destination)
             .await
             .map_err(|e| RezToolsError::ConfigError(format!("Failed to move file: {}", e)))?;
 
         info!(
-            "Downloaded {} bytes to {}",
+            "Downloaded and verified {} bytes to {}",
             downloaded,
             destination.display()
         );
@@ -215,6 +442,63 @@ impl DownloadClient {
 
         Ok(bytes.to_vec())
     }
+
+    /// Download and return content as bytes, verifying its SHA-256 digest
+    pub async fn download_bytes_verified(
+        &self,
+        url: &str,
+        expected_sha256: &str,
+    ) -> Result<Vec<u8>> {
+        let expected_sha256 = expected_sha256.to_lowercase();
+
+        for attempt in 1..=self.max_retries {
+            debug!(
+                "Downloading {} to memory (attempt {} of {}, verified)",
+                url, attempt, self.max_retries
+            );
+
+            match self.try_download_bytes(url).await {
+                Ok(bytes) => {
+                    let mut hasher = Sha256::new();
+                    hasher.update(&bytes);
+                    let actual_sha256 = format!("{:x}", hasher.finalize());
+
+                    if actual_sha256 != expected_sha256 {
+                        warn!(
+                            "Checksum mismatch for {}: expected {}, got {}",
+                            url, expected_sha256, actual_sha256
+                        );
+
+                        if attempt == self.max_retries {
+                            return Err(RezToolsError::ConfigError(format!(
+                                "Checksum mismatch for {}: expected {}, got {}",
+                                url, expected_sha256, actual_sha256
+                            )));
+                        }
+
+                        let delay = Duration::from_secs(2_u64.pow(attempt as u32 - 1));
+                        tokio::time::sleep(delay).await;
+                        continue;
+                    }
+
+                    debug!("Downloaded and verified {} bytes", bytes.len());
+                    return Ok(bytes);
+                }
+                Err(e) => {
+                    warn!("Download attempt {} failed: {}", attempt, e);
+
+                    if attempt == self.max_retries {
+                        return Err(e);
+                    }
+
+                    let delay = Duration::from_secs(2_u64.pow(attempt as u32 - 1));
+                    tokio::time::sleep(delay).await;
+                }
+            }
+        }
+
+        unreachable!()
+    }
 }
 
 #[cfg(test)]
@@ -272,6 +556,19 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn test_partial_tmp_file_length_is_resume_offset() {
+        let temp_dir = TempDir::new().unwrap();
+        let destination = temp_dir.path().join("test_file.txt");
+        let temp_path = destination.with_extension("tmp");
+
+        // Simulate a previous partial download
+        fs::write(&temp_path, b"partial").await.unwrap();
+
+        let metadata = fs::metadata(&temp_path).await.unwrap();
+        assert_eq!(metadata.len(), 7);
+    }
+
     #[test]
     fn test_download_client_creates_parent_directory() {
         // This test verifies that the download client would create parent directories
@@ -286,6 +583,62 @@ mod tests {
         // This is tested implicitly in the integration tests
     }
 
+    #[tokio::test]
+    async fn test_download_file_verified_invalid_url() {
+        let temp_dir = TempDir::new().unwrap();
+        let destination = temp_dir.path().join("test_file.txt");
+
+        let client = DownloadClient::new();
+
+        // Invalid URL still fails before the checksum is even reached, but this
+        // confirms the verified path is wired up and returns an error rather than panicking.
+        let result = client
+            .download_file_verified(
+                "http://invalid-url-that-does-not-exist.com/file.txt",
+                &destination,
+                "deadbeef",
+            )
+            .await;
+
+        assert!(result.is_err());
+        assert!(!destination.exists());
+    }
+
+    #[tokio::test]
+    async fn test_verify_checksum_matches_known_digest() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("archive.tar.gz");
+        fs::write(&path, b"hello world").await.unwrap();
+
+        // sha256("hello world")
+        let expected = "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9";
+        verify_checksum(&path, expected).await.unwrap();
+
+        // Hex comparison is case-insensitive
+        verify_checksum(&path, &expected.to_uppercase())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_verify_checksum_rejects_mismatch() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("archive.tar.gz");
+        fs::write(&path, b"hello world").await.unwrap();
+
+        let result = verify_checksum(&path, "deadbeef").await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_verify_checksum_missing_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("does-not-exist.tar.gz");
+
+        let result = verify_checksum(&path, "deadbeef").await;
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_exponential_backoff_calculation() {
         // Test that our exponential backoff calculation works correctly
@@ -0,0 +1,115 @@
+use crate::error::{Result, RezToolsError};
+use log::debug;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::{Mutex, OnceLock};
+
+/// Cache of executable name -> resolved, validated path, shared across
+/// callers so repeated lookups don't re-walk `PATH`
+static EXECUTABLE_CACHE: OnceLock<Mutex<HashMap<String, PathBuf>>> = OnceLock::new();
+
+fn cache() -> &'static Mutex<HashMap<String, PathBuf>> {
+    EXECUTABLE_CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Suffixes tried for a bare command name on Windows, taken from `PATHEXT`
+/// (falling back to a sane default if it isn't set)
+fn windows_exe_suffixes() -> Vec<String> {
+    std::env::var("PATHEXT")
+        .unwrap_or_else(|_| ".EXE;.CMD;.BAT;.COM".to_string())
+        .split(';')
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_string())
+        .collect()
+}
+
+/// Candidate file names to try for `name` in a single directory
+fn candidate_names(name: &str) -> Vec<String> {
+    if cfg!(windows) {
+        windows_exe_suffixes()
+            .into_iter()
+            .map(|suffix| format!("{}{}", name, suffix))
+            .collect()
+    } else {
+        vec![name.to_string()]
+    }
+}
+
+/// Confirm `candidate` is actually runnable (not just present) by spawning
+/// it with `--version` and checking for a clean exit
+pub(crate) fn is_runnable(candidate: &Path) -> bool {
+    Command::new(candidate)
+        .arg("--version")
+        .output()
+        .map(|output| output.status.success())
+        .unwrap_or(false)
+}
+
+/// Resolve the path to an executable named `name`, inspired by
+/// rust-analyzer's `get_path_for_executable`.
+///
+/// Tries each directory in `extra_dirs` first (e.g. a configured install's
+/// `bin` directory), then walks every entry of `PATH`. On Windows, each
+/// candidate stem is tried with every suffix in `PATHEXT`. Every candidate
+/// that exists is confirmed runnable via `--version` before being
+/// accepted, and the validated result is cached for subsequent calls.
+pub fn find_executable(name: &str, extra_dirs: &[PathBuf]) -> Result<PathBuf> {
+    if let Some(path) = cache().lock().unwrap().get(name) {
+        return Ok(path.clone());
+    }
+
+    let mut searched = Vec::new();
+    let path_var = std::env::var_os("PATH").unwrap_or_default();
+    let dirs = extra_dirs.iter().cloned().chain(std::env::split_paths(&path_var));
+
+    for dir in dirs {
+        for candidate_name in candidate_names(name) {
+            let candidate = dir.join(&candidate_name);
+            searched.push(candidate.display().to_string());
+
+            if candidate.is_file() && is_runnable(&candidate) {
+                debug!("Resolved executable '{}' to {}", name, candidate.display());
+                cache().lock().unwrap().insert(name.to_string(), candidate.clone());
+                return Ok(candidate);
+            }
+        }
+    }
+
+    Err(RezToolsError::ConfigError(format!(
+        "Executable '{}' not found or not runnable. Searched: {}",
+        name,
+        searched.join(", ")
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[cfg(unix)]
+    #[test]
+    fn finds_runnable_executable_in_extra_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        let script = temp_dir.path().join("fake-tool");
+        std::fs::write(&script, "#!/bin/sh\necho fake-tool 1.0\n").unwrap();
+        let mut perms = std::fs::metadata(&script).unwrap().permissions();
+        std::os::unix::fs::PermissionsExt::set_mode(&mut perms, 0o755);
+        std::fs::set_permissions(&script, perms).unwrap();
+
+        let resolved = find_executable("fake-tool", &[temp_dir.path().to_path_buf()]).unwrap();
+        assert_eq!(resolved, script);
+    }
+
+    #[test]
+    fn reports_searched_locations_when_missing() {
+        let temp_dir = TempDir::new().unwrap();
+        let err = find_executable(
+            "definitely-not-a-real-binary",
+            &[temp_dir.path().to_path_buf()],
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("definitely-not-a-real-binary"));
+    }
+}
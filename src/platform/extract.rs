@@ -1,16 +1,222 @@
 use crate::error::{Result, RezToolsError};
 use log::{debug, info};
-use std::path::Path;
+use std::path::{Component, Path, PathBuf};
 use tokio::fs;
 
+/// Caps on what a single extraction will unpack, to guard against zip bombs
+/// and path traversal. Defaults are generous but finite. `Extractor` and
+/// `extract_archive` always enforce `ExtractionLimits::default()`; the limits
+/// built by `Config::extraction_limits` are not applied by them.
+#[derive(Debug, Clone, Copy)]
+pub struct ExtractionLimits {
+    /// Maximum total uncompressed bytes across all entries
+    pub max_total_bytes: u64,
+    /// Maximum uncompressed bytes for any single entry
+    pub max_entry_bytes: u64,
+    /// Maximum number of entries in the archive
+    pub max_entry_count: u64,
+}
+
+impl Default for ExtractionLimits {
+    fn default() -> Self {
+        Self {
+            max_total_bytes: 10 * 1024u64.pow(4), // 10 TiB
+            max_entry_bytes: 1024u64.pow(4),       // 1 TiB
+            max_entry_count: 5_000_000,
+        }
+    }
+}
+
+/// Resolve an archive entry's path against `dest_dir`, rejecting anything
+/// that could escape it: parent-directory components, absolute paths, or
+/// Windows path prefixes.
+fn safe_entry_path(dest_dir: &Path, raw_path: &Path) -> Result<PathBuf> {
+    let mut relative = PathBuf::new();
+
+    for component in raw_path.components() {
+        match component {
+            Component::Normal(part) => relative.push(part),
+            Component::CurDir => {}
+            Component::ParentDir | Component::RootDir | Component::Prefix(_) => {
+                return Err(RezToolsError::ExtractionError(format!(
+                    "Archive entry '{}' escapes the destination directory",
+                    raw_path.display()
+                )));
+            }
+        }
+    }
+
+    Ok(dest_dir.join(relative))
+}
+
+/// Number of leading bytes to read when sniffing an archive's format. Large
+/// enough to cover the ustar magic, which lives at offset 257.
+const MAGIC_SNIFF_LEN: usize = 262;
+
+/// An archive format `Extractor` knows how to unpack, as inferred from file
+/// content (preferred) or file name (fallback/tiebreaker)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveFormat {
+    Zip,
+    Tar,
+    TarGz,
+    TarXz,
+    TarZst,
+    TarBz2,
+    Ar,
+    Gz,
+    Xz,
+    Bz2,
+    Zst,
+}
+
+impl ArchiveFormat {
+    /// Infer the format of the archive at `path` by sniffing its content,
+    /// falling back to its file name when the magic bytes are ambiguous
+    /// (e.g. a compressed stream could be a bare file or a wrapped tarball).
+    pub fn detect(path: &Path) -> Result<Self> {
+        let header = Self::read_header(path)?;
+        let tar_named = Self::is_tar_named(path);
+
+        if header.starts_with(b"PK\x03\x04")
+            || header.starts_with(b"PK\x05\x06")
+            || header.starts_with(b"PK\x07\x08")
+        {
+            return Ok(ArchiveFormat::Zip);
+        }
+        if header.starts_with(b"!<arch>\n") {
+            return Ok(ArchiveFormat::Ar);
+        }
+        if header.starts_with(&[0x1f, 0x8b]) {
+            return Ok(if tar_named {
+                ArchiveFormat::TarGz
+            } else {
+                ArchiveFormat::Gz
+            });
+        }
+        if header.starts_with(&[0x28, 0xb5, 0x2f, 0xfd]) {
+            return Ok(if tar_named {
+                ArchiveFormat::TarZst
+            } else {
+                ArchiveFormat::Zst
+            });
+        }
+        if header.starts_with(&[0xfd, b'7', b'z', b'X', b'Z', 0x00]) {
+            return Ok(if tar_named {
+                ArchiveFormat::TarXz
+            } else {
+                ArchiveFormat::Xz
+            });
+        }
+        if header.starts_with(b"BZh") {
+            return Ok(if tar_named {
+                ArchiveFormat::TarBz2
+            } else {
+                ArchiveFormat::Bz2
+            });
+        }
+        if header.len() >= 262 && &header[257..262] == b"ustar" {
+            return Ok(ArchiveFormat::Tar);
+        }
+
+        // Content sniffing was inconclusive (e.g. a test fixture with bogus
+        // bytes); fall back to the file name entirely.
+        Self::from_file_name(path)
+    }
+
+    /// Whether `path`'s name marks it as a tarball wrapped in a compression
+    /// stream, e.g. "release.tar.gz" rather than a bare "release.gz".
+    fn is_tar_named(path: &Path) -> bool {
+        let file_name = path
+            .file_name()
+            .and_then(|s| s.to_str())
+            .unwrap_or("")
+            .to_lowercase();
+
+        file_name.ends_with(".tar.gz")
+            || file_name.ends_with(".tgz")
+            || file_name.ends_with(".tar.xz")
+            || file_name.ends_with(".txz")
+            || file_name.ends_with(".tar.zst")
+            || file_name.ends_with(".tzst")
+            || file_name.ends_with(".tar.bz2")
+            || file_name.ends_with(".tbz2")
+    }
+
+    /// Infer the format purely from the file name, the same way `Extractor`
+    /// did before content sniffing was introduced.
+    fn from_file_name(path: &Path) -> Result<Self> {
+        let file_name = path
+            .file_name()
+            .and_then(|s| s.to_str())
+            .unwrap_or("")
+            .to_lowercase();
+        let extension = path
+            .extension()
+            .and_then(|s| s.to_str())
+            .unwrap_or("")
+            .to_lowercase();
+
+        if file_name.ends_with(".tar.gz") || file_name.ends_with(".tgz") {
+            Ok(ArchiveFormat::TarGz)
+        } else if file_name.ends_with(".tar.xz") || file_name.ends_with(".txz") {
+            Ok(ArchiveFormat::TarXz)
+        } else if file_name.ends_with(".tar.zst") || file_name.ends_with(".tzst") {
+            Ok(ArchiveFormat::TarZst)
+        } else if file_name.ends_with(".tar.bz2") || file_name.ends_with(".tbz2") {
+            Ok(ArchiveFormat::TarBz2)
+        } else {
+            match extension.as_str() {
+                "zip" => Ok(ArchiveFormat::Zip),
+                "tar" => Ok(ArchiveFormat::Tar),
+                "ar" => Ok(ArchiveFormat::Ar),
+                "gz" => Ok(ArchiveFormat::Gz),
+                "xz" => Ok(ArchiveFormat::Xz),
+                "bz2" => Ok(ArchiveFormat::Bz2),
+                "zst" => Ok(ArchiveFormat::Zst),
+                _ => Err(RezToolsError::ConfigError(format!(
+                    "Unsupported archive format: {}",
+                    extension
+                ))),
+            }
+        }
+    }
+
+    /// Read up to `MAGIC_SNIFF_LEN` leading bytes of `path` for magic-number
+    /// sniffing. Shorter files simply yield fewer bytes.
+    fn read_header(path: &Path) -> Result<Vec<u8>> {
+        use std::io::Read;
+
+        let file = std::fs::File::open(path)
+            .map_err(|e| RezToolsError::ConfigError(format!("Failed to open archive: {}", e)))?;
+        let mut buf = Vec::new();
+        file.take(MAGIC_SNIFF_LEN as u64)
+            .read_to_end(&mut buf)
+            .map_err(|e| {
+                RezToolsError::ConfigError(format!("Failed to read archive header: {}", e))
+            })?;
+        Ok(buf)
+    }
+}
+
 /// Archive extraction utilities
 pub struct Extractor;
 
 impl Extractor {
-    /// Extract an archive based on its file extension
+    /// Extract an archive, inferring its format from content and file name
     pub async fn extract<P: AsRef<Path>, Q: AsRef<Path>>(
         archive_path: P,
         extract_to: Q,
+    ) -> Result<()> {
+        Self::extract_with_format(archive_path, extract_to, None).await
+    }
+
+    /// Extract an archive, optionally forcing the format instead of inferring
+    /// it from the file's content and name
+    pub async fn extract_with_format<P: AsRef<Path>, Q: AsRef<Path>>(
+        archive_path: P,
+        extract_to: Q,
+        format: Option<ArchiveFormat>,
     ) -> Result<()> {
         let archive_path = archive_path.as_ref();
         let extract_to = extract_to.as_ref();
@@ -24,41 +230,46 @@ impl Extractor {
         // Ensure extraction directory exists
         fs::create_dir_all(extract_to).await?;
 
-        // Determine archive type by extension
-        let extension = archive_path
-            .extension()
-            .and_then(|s| s.to_str())
-            .unwrap_or("");
-
-        match extension.to_lowercase().as_str() {
-            "zip" => Self::extract_zip(archive_path, extract_to).await,
-            "gz" | "tgz" => {
-                // Check if it's a .tar.gz
-                let file_name = archive_path
-                    .file_name()
-                    .and_then(|s| s.to_str())
-                    .unwrap_or("");
-                if file_name.ends_with(".tar.gz") || file_name.ends_with(".tgz") {
-                    Self::extract_tar_gz(archive_path, extract_to).await
-                } else {
-                    Err(RezToolsError::ConfigError(format!(
-                        "Unsupported archive format: {}",
-                        extension
-                    )))
-                }
+        let format = match format {
+            Some(format) => format,
+            None => ArchiveFormat::detect(archive_path)?,
+        };
+
+        match format {
+            ArchiveFormat::Zip => Self::extract_zip(archive_path, extract_to).await,
+            ArchiveFormat::Tar => Self::extract_tar(archive_path, extract_to).await,
+            ArchiveFormat::TarGz => Self::extract_tar_gz(archive_path, extract_to).await,
+            ArchiveFormat::TarXz => Self::extract_tar_xz(archive_path, extract_to).await,
+            ArchiveFormat::TarZst => Self::extract_tar_zst(archive_path, extract_to).await,
+            ArchiveFormat::TarBz2 => Self::extract_tar_bz2(archive_path, extract_to).await,
+            ArchiveFormat::Ar => Self::extract_ar(archive_path, extract_to).await,
+            ArchiveFormat::Gz => {
+                Self::extract_bare(archive_path, extract_to, flate2::read::GzDecoder::new).await
+            }
+            ArchiveFormat::Xz => {
+                Self::extract_bare(archive_path, extract_to, xz2::read::XzDecoder::new).await
             }
-            "tar" => Self::extract_tar(archive_path, extract_to).await,
-            _ => Err(RezToolsError::ConfigError(format!(
-                "Unsupported archive format: {}",
-                extension
-            ))),
+            ArchiveFormat::Bz2 => {
+                Self::extract_bare(archive_path, extract_to, bzip2::read::BzDecoder::new).await
+            }
+            ArchiveFormat::Zst => Self::extract_bare_zst(archive_path, extract_to).await,
         }
     }
 
-    /// Extract ZIP archive
+    /// Extract ZIP archive, enforcing the default `ExtractionLimits`
     async fn extract_zip<P: AsRef<Path>, Q: AsRef<Path>>(
         archive_path: P,
         extract_to: Q,
+    ) -> Result<()> {
+        Self::extract_zip_with_limits(archive_path, extract_to, ExtractionLimits::default()).await
+    }
+
+    /// Extract ZIP archive, rejecting entries that escape `extract_to` or
+    /// exceed `limits`
+    async fn extract_zip_with_limits<P: AsRef<Path>, Q: AsRef<Path>>(
+        archive_path: P,
+        extract_to: Q,
+        limits: ExtractionLimits,
     ) -> Result<()> {
         let archive_path = archive_path.as_ref();
         let extract_to = extract_to.as_ref();
@@ -78,15 +289,45 @@ impl Extractor {
                 RezToolsError::ConfigError(format!("Failed to read ZIP archive: {}", e))
             })?;
 
+            if archive.len() as u64 > limits.max_entry_count {
+                return Err(RezToolsError::ExtractionError(format!(
+                    "Archive has {} entries, exceeding the limit of {}",
+                    archive.len(),
+                    limits.max_entry_count
+                )));
+            }
+
+            let mut total_bytes = 0u64;
+
             for i in 0..archive.len() {
                 let mut file = archive.by_index(i).map_err(|e| {
                     RezToolsError::ConfigError(format!("Failed to read ZIP entry: {}", e))
                 })?;
 
-                let outpath = match file.enclosed_name() {
-                    Some(path) => extract_to.join(path),
-                    None => continue,
-                };
+                let enclosed_name = file.enclosed_name().ok_or_else(|| {
+                    RezToolsError::ExtractionError(format!(
+                        "Archive entry '{}' has an unsafe path",
+                        file.name()
+                    ))
+                })?;
+                let outpath = safe_entry_path(&extract_to, &enclosed_name)?;
+
+                let entry_size = file.size();
+                if entry_size > limits.max_entry_bytes {
+                    return Err(RezToolsError::ExtractionError(format!(
+                        "Archive entry '{}' is {} bytes, exceeding the per-entry limit of {}",
+                        file.name(),
+                        entry_size,
+                        limits.max_entry_bytes
+                    )));
+                }
+                total_bytes += entry_size;
+                if total_bytes > limits.max_total_bytes {
+                    return Err(RezToolsError::ExtractionError(format!(
+                        "Archive exceeds the total uncompressed size limit of {} bytes",
+                        limits.max_total_bytes
+                    )));
+                }
 
                 if file.name().ends_with('/') {
                     // Directory
@@ -94,16 +335,16 @@ impl Extractor {
                         RezToolsError::ConfigError(format!("Failed to create directory: {}", e))
                     })?;
                 } else {
-                    // File
+                    // File. Always (re)create the parent, since archives commonly
+                    // omit standalone directory entries for folders that only
+                    // ever appear as a prefix of a file path.
                     if let Some(p) = outpath.parent() {
-                        if !p.exists() {
-                            std::fs::create_dir_all(p).map_err(|e| {
-                                RezToolsError::ConfigError(format!(
-                                    "Failed to create directory: {}",
-                                    e
-                                ))
-                            })?;
-                        }
+                        std::fs::create_dir_all(p).map_err(|e| {
+                            RezToolsError::ConfigError(format!(
+                                "Failed to create directory: {}",
+                                e
+                            ))
+                        })?;
                     }
 
                     let mut outfile = std::fs::File::create(&outpath).map_err(|e| {
@@ -142,28 +383,18 @@ impl Extractor {
         archive_path: P,
         extract_to: Q,
     ) -> Result<()> {
-        let archive_path = archive_path.as_ref();
-        let extract_to = extract_to.as_ref();
+        let archive_path = archive_path.as_ref().to_path_buf();
+        let extract_to = extract_to.as_ref().to_path_buf();
 
         debug!("Extracting TAR.GZ archive");
 
-        // Use blocking task for CPU-intensive work
-        let archive_path = archive_path.to_path_buf();
-        let extract_to = extract_to.to_path_buf();
-
         tokio::task::spawn_blocking(move || {
             let file = std::fs::File::open(&archive_path).map_err(|e| {
                 RezToolsError::ConfigError(format!("Failed to open archive: {}", e))
             })?;
 
             let decoder = flate2::read::GzDecoder::new(file);
-            let mut archive = tar::Archive::new(decoder);
-
-            archive.unpack(&extract_to).map_err(|e| {
-                RezToolsError::ConfigError(format!("Failed to extract TAR.GZ archive: {}", e))
-            })?;
-
-            Ok::<(), RezToolsError>(())
+            unpack_tar_checked(decoder, &extract_to, ExtractionLimits::default(), false)
         })
         .await
         .map_err(|e| RezToolsError::ConfigError(format!("Extraction task failed: {}", e)))??;
@@ -177,34 +408,380 @@ impl Extractor {
         archive_path: P,
         extract_to: Q,
     ) -> Result<()> {
-        let archive_path = archive_path.as_ref();
-        let extract_to = extract_to.as_ref();
+        let archive_path = archive_path.as_ref().to_path_buf();
+        let extract_to = extract_to.as_ref().to_path_buf();
 
         debug!("Extracting TAR archive");
 
-        // Use blocking task for CPU-intensive work
-        let archive_path = archive_path.to_path_buf();
-        let extract_to = extract_to.to_path_buf();
+        tokio::task::spawn_blocking(move || {
+            let file = std::fs::File::open(&archive_path).map_err(|e| {
+                RezToolsError::ConfigError(format!("Failed to open archive: {}", e))
+            })?;
+
+            unpack_tar_checked(file, &extract_to, ExtractionLimits::default(), false)
+        })
+        .await
+        .map_err(|e| RezToolsError::ConfigError(format!("Extraction task failed: {}", e)))??;
+
+        info!("TAR extraction completed");
+        Ok(())
+    }
+
+    /// Extract TAR.XZ archive
+    async fn extract_tar_xz<P: AsRef<Path>, Q: AsRef<Path>>(
+        archive_path: P,
+        extract_to: Q,
+    ) -> Result<()> {
+        let archive_path = archive_path.as_ref().to_path_buf();
+        let extract_to = extract_to.as_ref().to_path_buf();
+
+        debug!("Extracting TAR.XZ archive");
 
         tokio::task::spawn_blocking(move || {
             let file = std::fs::File::open(&archive_path).map_err(|e| {
                 RezToolsError::ConfigError(format!("Failed to open archive: {}", e))
             })?;
 
-            let mut archive = tar::Archive::new(file);
+            let decoder = xz2::read::XzDecoder::new(file);
+            unpack_tar_checked(decoder, &extract_to, ExtractionLimits::default(), false)
+        })
+        .await
+        .map_err(|e| RezToolsError::ConfigError(format!("Extraction task failed: {}", e)))??;
+
+        info!("TAR.XZ extraction completed");
+        Ok(())
+    }
+
+    /// Extract TAR.ZST archive
+    async fn extract_tar_zst<P: AsRef<Path>, Q: AsRef<Path>>(
+        archive_path: P,
+        extract_to: Q,
+    ) -> Result<()> {
+        let archive_path = archive_path.as_ref().to_path_buf();
+        let extract_to = extract_to.as_ref().to_path_buf();
+
+        debug!("Extracting TAR.ZST archive");
+
+        tokio::task::spawn_blocking(move || {
+            let file = std::fs::File::open(&archive_path).map_err(|e| {
+                RezToolsError::ConfigError(format!("Failed to open archive: {}", e))
+            })?;
 
-            archive.unpack(&extract_to).map_err(|e| {
-                RezToolsError::ConfigError(format!("Failed to extract TAR archive: {}", e))
+            let decoder = zstd::Decoder::new(file).map_err(|e| {
+                RezToolsError::ConfigError(format!("Failed to open zstd stream: {}", e))
             })?;
+            unpack_tar_checked(decoder, &extract_to, ExtractionLimits::default(), false)
+        })
+        .await
+        .map_err(|e| RezToolsError::ConfigError(format!("Extraction task failed: {}", e)))??;
+
+        info!("TAR.ZST extraction completed");
+        Ok(())
+    }
+
+    /// Extract TAR.BZ2 archive
+    async fn extract_tar_bz2<P: AsRef<Path>, Q: AsRef<Path>>(
+        archive_path: P,
+        extract_to: Q,
+    ) -> Result<()> {
+        let archive_path = archive_path.as_ref().to_path_buf();
+        let extract_to = extract_to.as_ref().to_path_buf();
+
+        debug!("Extracting TAR.BZ2 archive");
+
+        tokio::task::spawn_blocking(move || {
+            let file = std::fs::File::open(&archive_path).map_err(|e| {
+                RezToolsError::ConfigError(format!("Failed to open archive: {}", e))
+            })?;
+
+            let decoder = bzip2::read::BzDecoder::new(file);
+            unpack_tar_checked(decoder, &extract_to, ExtractionLimits::default(), false)
+        })
+        .await
+        .map_err(|e| RezToolsError::ConfigError(format!("Extraction task failed: {}", e)))??;
+
+        info!("TAR.BZ2 extraction completed");
+        Ok(())
+    }
+
+    /// Extract a plain `.ar` archive (no compression)
+    async fn extract_ar<P: AsRef<Path>, Q: AsRef<Path>>(
+        archive_path: P,
+        extract_to: Q,
+    ) -> Result<()> {
+        let archive_path = archive_path.as_ref().to_path_buf();
+        let extract_to = extract_to.as_ref().to_path_buf();
+
+        debug!("Extracting AR archive");
+
+        tokio::task::spawn_blocking(move || {
+            let file = std::fs::File::open(&archive_path).map_err(|e| {
+                RezToolsError::ConfigError(format!("Failed to open archive: {}", e))
+            })?;
+
+            let mut archive = ar::Archive::new(file);
+
+            while let Some(entry) = archive.next_entry() {
+                let mut entry = entry.map_err(|e| {
+                    RezToolsError::ConfigError(format!("Failed to read AR entry: {}", e))
+                })?;
+
+                let name = String::from_utf8_lossy(entry.header().identifier()).to_string();
+                let out_path = extract_to.join(&name);
+
+                if let Some(parent) = out_path.parent() {
+                    std::fs::create_dir_all(parent).map_err(|e| {
+                        RezToolsError::ConfigError(format!("Failed to create directory: {}", e))
+                    })?;
+                }
+
+                let mut outfile = std::fs::File::create(&out_path).map_err(|e| {
+                    RezToolsError::ConfigError(format!("Failed to create file: {}", e))
+                })?;
+
+                std::io::copy(&mut entry, &mut outfile).map_err(|e| {
+                    RezToolsError::ConfigError(format!("Failed to extract AR entry: {}", e))
+                })?;
+            }
 
             Ok::<(), RezToolsError>(())
         })
         .await
         .map_err(|e| RezToolsError::ConfigError(format!("Extraction task failed: {}", e)))??;
 
-        info!("TAR extraction completed");
+        info!("AR extraction completed");
         Ok(())
     }
+
+    /// Decompress a bare single-file archive (`.gz`, `.xz`, `.bz2`) to a file
+    /// of the same name minus the compression extension.
+    async fn extract_bare<P, Q, D, F>(archive_path: P, extract_to: Q, make_decoder: F) -> Result<()>
+    where
+        P: AsRef<Path>,
+        Q: AsRef<Path>,
+        D: std::io::Read + Send + 'static,
+        F: FnOnce(std::fs::File) -> D + Send + 'static,
+    {
+        let archive_path = archive_path.as_ref().to_path_buf();
+        let extract_to = extract_to.as_ref().to_path_buf();
+        let out_name = bare_output_name(&archive_path);
+
+        debug!("Decompressing bare archive to {}", out_name);
+
+        tokio::task::spawn_blocking(move || {
+            let file = std::fs::File::open(&archive_path).map_err(|e| {
+                RezToolsError::ConfigError(format!("Failed to open archive: {}", e))
+            })?;
+
+            let mut decoder = make_decoder(file);
+            let mut outfile = std::fs::File::create(extract_to.join(&out_name)).map_err(|e| {
+                RezToolsError::ConfigError(format!("Failed to create file: {}", e))
+            })?;
+
+            std::io::copy(&mut decoder, &mut outfile).map_err(|e| {
+                RezToolsError::ConfigError(format!("Failed to decompress archive: {}", e))
+            })?;
+
+            Ok::<(), RezToolsError>(())
+        })
+        .await
+        .map_err(|e| RezToolsError::ConfigError(format!("Extraction task failed: {}", e)))??;
+
+        info!("Decompression completed");
+        Ok(())
+    }
+
+    /// Decompress a bare `.zst` file (zstd's decoder constructor is fallible,
+    /// so it can't share the generic `extract_bare` helper)
+    async fn extract_bare_zst<P: AsRef<Path>, Q: AsRef<Path>>(
+        archive_path: P,
+        extract_to: Q,
+    ) -> Result<()> {
+        let archive_path = archive_path.as_ref().to_path_buf();
+        let extract_to = extract_to.as_ref().to_path_buf();
+        let out_name = bare_output_name(&archive_path);
+
+        debug!("Decompressing bare zstd archive to {}", out_name);
+
+        tokio::task::spawn_blocking(move || {
+            let file = std::fs::File::open(&archive_path).map_err(|e| {
+                RezToolsError::ConfigError(format!("Failed to open archive: {}", e))
+            })?;
+
+            let mut decoder = zstd::Decoder::new(file).map_err(|e| {
+                RezToolsError::ConfigError(format!("Failed to open zstd stream: {}", e))
+            })?;
+            let mut outfile = std::fs::File::create(extract_to.join(&out_name)).map_err(|e| {
+                RezToolsError::ConfigError(format!("Failed to create file: {}", e))
+            })?;
+
+            std::io::copy(&mut decoder, &mut outfile).map_err(|e| {
+                RezToolsError::ConfigError(format!("Failed to decompress archive: {}", e))
+            })?;
+
+            Ok::<(), RezToolsError>(())
+        })
+        .await
+        .map_err(|e| RezToolsError::ConfigError(format!("Extraction task failed: {}", e)))??;
+
+        info!("Decompression completed");
+        Ok(())
+    }
+}
+
+/// Derive the output file name for a bare compressed file by stripping its
+/// compression extension, e.g. "foo.txt.gz" -> "foo.txt"
+fn bare_output_name(archive_path: &Path) -> String {
+    let file_name = archive_path
+        .file_name()
+        .and_then(|s| s.to_str())
+        .unwrap_or("output");
+
+    archive_path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .map(|s| s.to_string())
+        .unwrap_or_else(|| file_name.to_string())
+}
+
+/// Extract a downloaded Python Build Standalone archive into `dest_dir`.
+///
+/// Handles the `.tar.gz`, `.tar.zst` and `.tar.xz` formats that Python Build
+/// Standalone releases ship, chosen by file name only. Unlike
+/// [`Extractor::extract`], it can strip the single top-level `python/`
+/// directory these archives contain so the interpreter always lands at a
+/// predictable path.
+pub async fn extract_archive<P: AsRef<Path>, Q: AsRef<Path>>(
+    archive_path: P,
+    dest_dir: Q,
+    strip_top_level: bool,
+) -> Result<()> {
+    let archive_path = archive_path.as_ref().to_path_buf();
+    let dest_dir = dest_dir.as_ref().to_path_buf();
+
+    info!(
+        "Extracting {} to {}",
+        archive_path.display(),
+        dest_dir.display()
+    );
+
+    fs::create_dir_all(&dest_dir).await?;
+
+    let file_name = archive_path
+        .file_name()
+        .and_then(|s| s.to_str())
+        .unwrap_or("")
+        .to_lowercase();
+
+    tokio::task::spawn_blocking(move || {
+        let file = std::fs::File::open(&archive_path).map_err(|e| {
+            RezToolsError::ConfigError(format!("Failed to open archive: {}", e))
+        })?;
+
+        if file_name.ends_with(".tar.gz") || file_name.ends_with(".tgz") {
+            let decoder = flate2::read::GzDecoder::new(file);
+            unpack_tar_checked(decoder, &dest_dir, ExtractionLimits::default(), strip_top_level)
+        } else if file_name.ends_with(".tar.zst") {
+            let decoder = zstd::Decoder::new(file).map_err(|e| {
+                RezToolsError::ConfigError(format!("Failed to open zstd stream: {}", e))
+            })?;
+            unpack_tar_checked(decoder, &dest_dir, ExtractionLimits::default(), strip_top_level)
+        } else if file_name.ends_with(".tar.xz") {
+            let decoder = xz2::read::XzDecoder::new(file);
+            unpack_tar_checked(decoder, &dest_dir, ExtractionLimits::default(), strip_top_level)
+        } else {
+            Err(RezToolsError::ConfigError(format!(
+                "Unsupported Python Build Standalone archive format: {}",
+                file_name
+            )))
+        }
+    })
+    .await
+    .map_err(|e| RezToolsError::ConfigError(format!("Extraction task failed: {}", e)))??;
+
+    info!("Extraction completed");
+    Ok(())
+}
+
+/// Stream-unpack a tar archive from `reader`, rejecting entries that escape
+/// `dest_dir` or exceed `limits`. When `strip_top_level` is set, the leading
+/// path component of every entry is dropped (e.g. Python Build Standalone's
+/// `python/` wrapper directory), exactly as `safe_entry_path` would validate
+/// it if it had never been there. Used by all of `Extractor`'s tar variants
+/// as well as `extract_archive`.
+fn unpack_tar_checked<R: std::io::Read>(
+    reader: R,
+    dest_dir: &Path,
+    limits: ExtractionLimits,
+    strip_top_level: bool,
+) -> Result<()> {
+    let mut archive = tar::Archive::new(reader);
+    let mut total_bytes = 0u64;
+    let mut entry_count = 0u64;
+
+    for entry in archive
+        .entries()
+        .map_err(|e| RezToolsError::ConfigError(format!("Failed to read archive: {}", e)))?
+    {
+        let mut entry = entry
+            .map_err(|e| RezToolsError::ConfigError(format!("Failed to read entry: {}", e)))?;
+
+        entry_count += 1;
+        if entry_count > limits.max_entry_count {
+            return Err(RezToolsError::ExtractionError(format!(
+                "Archive has more than {} entries",
+                limits.max_entry_count
+            )));
+        }
+
+        let raw_path = entry
+            .path()
+            .map_err(|e| RezToolsError::ConfigError(format!("Invalid entry path: {}", e)))?
+            .into_owned();
+
+        let entry_path = if strip_top_level {
+            // Drop the leading "python/" (or whatever the top-level dir is named)
+            let mut components = raw_path.components();
+            components.next();
+            let stripped: PathBuf = components.collect();
+            if stripped.as_os_str().is_empty() {
+                continue;
+            }
+            stripped
+        } else {
+            raw_path.clone()
+        };
+        let out_path = safe_entry_path(dest_dir, &entry_path)?;
+
+        // GNU sparse entries report their apparent (sparse) size via `size()`;
+        // the real number of bytes written is in the GNU header extension.
+        let entry_size = entry
+            .header()
+            .as_gnu()
+            .and_then(|gnu| gnu.real_size().ok())
+            .unwrap_or_else(|| entry.size());
+        if entry_size > limits.max_entry_bytes {
+            return Err(RezToolsError::ExtractionError(format!(
+                "Archive entry '{}' is {} bytes, exceeding the per-entry limit of {}",
+                raw_path.display(),
+                entry_size,
+                limits.max_entry_bytes
+            )));
+        }
+        total_bytes += entry_size;
+        if total_bytes > limits.max_total_bytes {
+            return Err(RezToolsError::ExtractionError(format!(
+                "Archive exceeds the total uncompressed size limit of {} bytes",
+                limits.max_total_bytes
+            )));
+        }
+
+        entry.unpack(&out_path).map_err(|e| {
+            RezToolsError::ConfigError(format!("Failed to unpack entry: {}", e))
+        })?;
+    }
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -375,4 +952,304 @@ mod tests {
         // The future exists, proving the method is static
         assert!(true);
     }
+
+    #[tokio::test]
+    async fn test_extract_archive_tar_gz_strips_top_level() {
+        let temp_dir = TempDir::new().unwrap();
+        let archive_path = temp_dir.path().join("cpython.tar.gz");
+        let extract_to = temp_dir.path().join("extracted");
+
+        // Build a minimal tar.gz with a single top-level "python/" directory
+        let tar_bytes = {
+            let mut builder = tar::Builder::new(Vec::new());
+            let data = b"#!/bin/sh\n";
+            let mut header = tar::Header::new_gnu();
+            header.set_size(data.len() as u64);
+            header.set_mode(0o755);
+            header.set_cksum();
+            builder
+                .append_data(&mut header, "python/bin/python3", &data[..])
+                .unwrap();
+            builder.into_inner().unwrap()
+        };
+
+        let gz_bytes = {
+            use std::io::Write;
+            let mut encoder =
+                flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+            encoder.write_all(&tar_bytes).unwrap();
+            encoder.finish().unwrap()
+        };
+
+        fs::write(&archive_path, gz_bytes).unwrap();
+
+        extract_archive(&archive_path, &extract_to, true)
+            .await
+            .unwrap();
+
+        assert!(extract_to.join("bin").join("python3").exists());
+        assert!(!extract_to.join("python").exists());
+    }
+
+    #[tokio::test]
+    async fn test_extract_archive_unsupported_format() {
+        let temp_dir = TempDir::new().unwrap();
+        let archive_path = temp_dir.path().join("test.unknown");
+        let extract_to = temp_dir.path().join("extracted");
+
+        fs::write(&archive_path, "fake archive content").unwrap();
+
+        let result = extract_archive(&archive_path, &extract_to, false).await;
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_bare_output_name_strips_compression_extension() {
+        assert_eq!(bare_output_name(Path::new("data.txt.gz")), "data.txt");
+        assert_eq!(bare_output_name(Path::new("archive.xz")), "archive");
+        assert_eq!(bare_output_name(Path::new("payload.zst")), "payload");
+    }
+
+    #[tokio::test]
+    async fn test_extract_bare_gz_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let archive_path = temp_dir.path().join("hello.txt.gz");
+        let extract_to = temp_dir.path().join("out");
+
+        let gz_bytes = {
+            use std::io::Write;
+            let mut encoder =
+                flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+            encoder.write_all(b"hello world").unwrap();
+            encoder.finish().unwrap()
+        };
+        fs::write(&archive_path, gz_bytes).unwrap();
+
+        Extractor::extract(&archive_path, &extract_to).await.unwrap();
+
+        let extracted = fs::read(extract_to.join("hello.txt")).unwrap();
+        assert_eq!(extracted, b"hello world");
+    }
+
+    #[test]
+    fn test_archive_format_detect_sniffs_zip_magic_regardless_of_extension() {
+        let temp_dir = TempDir::new().unwrap();
+        let archive_path = temp_dir.path().join("mystery.bin");
+        fs::write(&archive_path, b"PK\x03\x04rest of a real zip would go here").unwrap();
+
+        assert_eq!(ArchiveFormat::detect(&archive_path).unwrap(), ArchiveFormat::Zip);
+    }
+
+    #[test]
+    fn test_archive_format_detect_distinguishes_bare_gz_from_tar_gz() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let bare_path = temp_dir.path().join("data.txt.gz");
+        fs::write(&bare_path, [0x1f, 0x8b, 0x08, 0x00]).unwrap();
+        assert_eq!(ArchiveFormat::detect(&bare_path).unwrap(), ArchiveFormat::Gz);
+
+        let tar_path = temp_dir.path().join("release.tar.gz");
+        fs::write(&tar_path, [0x1f, 0x8b, 0x08, 0x00]).unwrap();
+        assert_eq!(
+            ArchiveFormat::detect(&tar_path).unwrap(),
+            ArchiveFormat::TarGz
+        );
+    }
+
+    #[test]
+    fn test_archive_format_detect_falls_back_to_extension_for_unrecognized_content() {
+        let temp_dir = TempDir::new().unwrap();
+        let archive_path = temp_dir.path().join("archive.tar");
+        fs::write(&archive_path, b"not actually a tar header").unwrap();
+
+        assert_eq!(ArchiveFormat::detect(&archive_path).unwrap(), ArchiveFormat::Tar);
+    }
+
+    #[tokio::test]
+    async fn test_extract_with_format_override_extracts_forced_format() {
+        let temp_dir = TempDir::new().unwrap();
+        let archive_path = temp_dir.path().join("hello.txt.gz");
+        let extract_to = temp_dir.path().join("out");
+
+        let gz_bytes = {
+            use std::io::Write;
+            let mut encoder =
+                flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+            encoder.write_all(b"hello world").unwrap();
+            encoder.finish().unwrap()
+        };
+        fs::write(&archive_path, gz_bytes).unwrap();
+
+        Extractor::extract_with_format(&archive_path, &extract_to, Some(ArchiveFormat::Gz))
+            .await
+            .unwrap();
+
+        let extracted = fs::read(extract_to.join("hello.txt")).unwrap();
+        assert_eq!(extracted, b"hello world");
+    }
+
+    #[test]
+    fn test_safe_entry_path_rejects_parent_dir() {
+        let dest = Path::new("/tmp/dest");
+        let result = safe_entry_path(dest, Path::new("../escape.txt"));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_safe_entry_path_rejects_absolute_path() {
+        let dest = Path::new("/tmp/dest");
+        let result = safe_entry_path(dest, Path::new("/etc/passwd"));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_safe_entry_path_accepts_normal_path() {
+        let dest = Path::new("/tmp/dest");
+        let result = safe_entry_path(dest, Path::new("nested/file.txt")).unwrap();
+        assert_eq!(result, dest.join("nested").join("file.txt"));
+    }
+
+    #[tokio::test]
+    async fn test_unpack_tar_checked_rejects_path_traversal() {
+        let tar_bytes = {
+            let mut builder = tar::Builder::new(Vec::new());
+            let data = b"payload";
+            let mut header = tar::Header::new_gnu();
+            // `append_data` refuses `..` paths, so write the name bytes directly
+            let name = b"../escape.txt";
+            header.as_old_mut().name[..name.len()].copy_from_slice(name);
+            header.set_size(data.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder.append(&header, &data[..]).unwrap();
+            builder.into_inner().unwrap()
+        };
+
+        let temp_dir = TempDir::new().unwrap();
+        let dest_dir = temp_dir.path().join("dest");
+        fs::create_dir_all(&dest_dir).unwrap();
+
+        let result = unpack_tar_checked(&tar_bytes[..], &dest_dir, ExtractionLimits::default(), false);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("escapes"));
+    }
+
+    #[tokio::test]
+    async fn test_unpack_tar_checked_rejects_oversized_entry() {
+        let tar_bytes = {
+            let mut builder = tar::Builder::new(Vec::new());
+            let data = b"payload";
+            let mut header = tar::Header::new_gnu();
+            header.set_size(data.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder.append_data(&mut header, "file.txt", &data[..]).unwrap();
+            builder.into_inner().unwrap()
+        };
+
+        let temp_dir = TempDir::new().unwrap();
+        let dest_dir = temp_dir.path().join("dest");
+        fs::create_dir_all(&dest_dir).unwrap();
+
+        let limits = ExtractionLimits {
+            max_total_bytes: 100,
+            max_entry_bytes: 1,
+            max_entry_count: 100,
+        };
+        let result = unpack_tar_checked(&tar_bytes[..], &dest_dir, limits, false);
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("exceeding the per-entry limit"));
+    }
+
+    #[tokio::test]
+    async fn test_unpack_tar_checked_rejects_too_many_entries() {
+        let tar_bytes = {
+            let mut builder = tar::Builder::new(Vec::new());
+            for i in 0..3 {
+                let data = b"x";
+                let mut header = tar::Header::new_gnu();
+                header.set_size(data.len() as u64);
+                header.set_mode(0o644);
+                header.set_cksum();
+                builder
+                    .append_data(&mut header, format!("file{}.txt", i), &data[..])
+                    .unwrap();
+            }
+            builder.into_inner().unwrap()
+        };
+
+        let temp_dir = TempDir::new().unwrap();
+        let dest_dir = temp_dir.path().join("dest");
+        fs::create_dir_all(&dest_dir).unwrap();
+
+        let limits = ExtractionLimits {
+            max_total_bytes: 1024,
+            max_entry_bytes: 1024,
+            max_entry_count: 2,
+        };
+        let result = unpack_tar_checked(&tar_bytes[..], &dest_dir, limits, false);
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("more than 2 entries"));
+    }
+
+    #[tokio::test]
+    async fn test_extract_zip_creates_implicit_parent_directories() {
+        let temp_dir = TempDir::new().unwrap();
+        let archive_path = temp_dir.path().join("nested.zip");
+        let extract_to = temp_dir.path().join("out");
+
+        // Build a zip whose only entry is a deeply nested file, with no
+        // standalone directory records for "nested/" or "nested/dir/".
+        {
+            use std::io::Write;
+            let file = std::fs::File::create(&archive_path).unwrap();
+            let mut writer = zip::ZipWriter::new(file);
+            let options = zip::write::FileOptions::default();
+            writer.start_file("nested/dir/file.txt", options).unwrap();
+            writer.write_all(b"contents").unwrap();
+            writer.finish().unwrap();
+        }
+
+        Extractor::extract(&archive_path, &extract_to).await.unwrap();
+
+        let extracted = fs::read(extract_to.join("nested").join("dir").join("file.txt")).unwrap();
+        assert_eq!(extracted, b"contents");
+    }
+
+    #[tokio::test]
+    async fn test_extract_tar_bz2_archive() {
+        let temp_dir = TempDir::new().unwrap();
+        let archive_path = temp_dir.path().join("archive.tar.bz2");
+        let extract_to = temp_dir.path().join("out");
+
+        let tar_bytes = {
+            let mut builder = tar::Builder::new(Vec::new());
+            let data = b"payload";
+            let mut header = tar::Header::new_gnu();
+            header.set_size(data.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder.append_data(&mut header, "file.txt", &data[..]).unwrap();
+            builder.into_inner().unwrap()
+        };
+
+        let bz2_bytes = {
+            use std::io::Write;
+            let mut encoder =
+                bzip2::write::BzEncoder::new(Vec::new(), bzip2::Compression::default());
+            encoder.write_all(&tar_bytes).unwrap();
+            encoder.finish().unwrap()
+        };
+        fs::write(&archive_path, bz2_bytes).unwrap();
+
+        Extractor::extract(&archive_path, &extract_to).await.unwrap();
+
+        assert!(extract_to.join("file.txt").exists());
+    }
 }
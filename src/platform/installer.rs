@@ -1,26 +1,52 @@
 use crate::error::{Result, RezToolsError};
-use crate::platform::{python_standalone::PythonStandalone, Platform};
+use crate::platform::{
+    executable, lock,
+    python_standalone::{PythonRequest, PythonStandalone},
+    timeout::{run_with_timeout, CommandTimeouts},
+    Platform,
+};
 use log::{debug, info, warn};
 use std::path::{Path, PathBuf};
 use tokio::fs;
 use tokio::process::Command as AsyncCommand;
 
-/// Install rez using the best available method
-pub async fn install_rez() -> Result<()> {
+/// Install rez using the best available method, optionally pinned to a
+/// PEP 440 version specifier such as `==2.114.0` or `>=2.110,<3`. When
+/// `refresh_lock` is false and a `~/.rez-tools/rez.lock` from a prior
+/// install exists, the managed-venv and standalone install paths install
+/// from that lockfile's pinned versions instead of resolving again; pass
+/// `true` to re-resolve and overwrite the lockfile.
+pub async fn install_rez(
+    version_spec: Option<&str>,
+    refresh_lock: bool,
+    timeouts: CommandTimeouts,
+) -> Result<()> {
     info!("Installing rez...");
+    let requirement = build_requirement("rez", version_spec)?;
 
     // Try different installation methods in order of preference
-    if try_install_with_uv().await.is_ok() {
+    if try_install_rez_as_uv_tool(&requirement, timeouts).await.is_ok() {
+        info!("Successfully installed rez using uv tool install");
+        return Ok(());
+    }
+
+    if try_install_with_uv(&requirement, refresh_lock, timeouts)
+        .await
+        .is_ok()
+    {
         info!("Successfully installed rez using uv");
         return Ok(());
     }
 
-    if try_install_with_pip().await.is_ok() {
+    if try_install_with_pip(&requirement, timeouts).await.is_ok() {
         info!("Successfully installed rez using pip");
         return Ok(());
     }
 
-    if try_install_python_build_standalone().await.is_ok() {
+    if try_install_python_build_standalone(&requirement, refresh_lock, None, timeouts)
+        .await
+        .is_ok()
+    {
         info!("Successfully installed Python Build Standalone with rez");
         return Ok(());
     }
@@ -30,45 +56,154 @@ pub async fn install_rez() -> Result<()> {
     ))
 }
 
-/// Try to install rez using uv
-async fn try_install_with_uv() -> Result<()> {
-    debug!("Attempting to install rez with uv");
+/// Operators accepted in a PEP 440-style version specifier clause, ordered
+/// longest-first so `>=`/`<=`/`~=`/`==`/`!=` are matched before the shorter
+/// `>`/`<` they'd otherwise be mistaken for
+const VERSION_SPEC_OPERATORS: &[&str] = &["==", "!=", ">=", "<=", "~=", ">", "<"];
+
+/// Validate a PEP 440-ish, comma-separated version specifier (e.g.
+/// `==2.114.0` or `>=2.110,<3`) and build the full pip/uv requirement
+/// string for `package` (e.g. `rez==2.114.0`), so malformed input is
+/// rejected before it ever reaches pip/uv. `version_spec` of `None` yields
+/// the bare package name.
+fn build_requirement(package: &str, version_spec: Option<&str>) -> Result<String> {
+    let Some(spec) = version_spec else {
+        return Ok(package.to_string());
+    };
 
-    // Check if uv is available
-    let uv_check = AsyncCommand::new("uv").arg("--version").output().await;
+    for clause in spec.split(',') {
+        validate_version_spec_clause(clause.trim())?;
+    }
 
-    if let Err(e) = uv_check {
-        debug!("uv not found: {}", e);
-        return Err(RezToolsError::ConfigError("uv not found".to_string()));
+    Ok(format!("{}{}", package, spec))
+}
+
+/// Validate a single comma-separated clause of a version specifier, e.g.
+/// `>=2.110` or `==2.114.0`
+fn validate_version_spec_clause(clause: &str) -> Result<()> {
+    let operator = VERSION_SPEC_OPERATORS
+        .iter()
+        .find(|op| clause.starts_with(**op))
+        .ok_or_else(|| invalid_version_spec(clause))?;
+
+    let release = &clause[operator.len()..];
+    let is_valid_release = !release.is_empty()
+        && release
+            .split('.')
+            .all(|segment| !segment.is_empty() && segment.chars().all(|c| c.is_ascii_digit() || c == '*'));
+
+    if !is_valid_release {
+        return Err(invalid_version_spec(clause));
     }
 
-    // Create virtual environment
-    let venv_path = get_rez_tools_dir().join("venv");
+    Ok(())
+}
 
-    let output = AsyncCommand::new("uv")
-        .args(["venv", venv_path.to_string_lossy().as_ref()])
-        .output()
-        .await
-        .map_err(|e| RezToolsError::ConfigError(format!("Failed to create venv: {}", e)))?;
+fn invalid_version_spec(clause: &str) -> RezToolsError {
+    RezToolsError::ConfigError(format!(
+        "Invalid version specifier clause: '{}' (expected e.g. '==2.114.0' or '>=2.110')",
+        clause
+    ))
+}
+
+/// Try to install rez via uv's tool-installation subsystem (`uv tool
+/// install`), preferred over the hand-rolled `uv venv` + `pip install` +
+/// wrapper-script path in `try_install_with_uv`. `uv tool install` puts rez
+/// in its own isolated, reproducible environment and materializes a `rez`
+/// launcher into uv's central tool bin directory itself, so there is no
+/// wrapper script to synthesize and `uv tool upgrade rez` / `uv tool
+/// uninstall rez` keep working afterwards.
+async fn try_install_rez_as_uv_tool(requirement: &str, timeouts: CommandTimeouts) -> Result<PathBuf> {
+    debug!("Attempting to install {} with `uv tool install`", requirement);
+
+    let mut cmd = AsyncCommand::new("uv");
+    cmd.args(["tool", "install", requirement]);
+    let output = run_with_timeout(cmd, timeouts.install).await?;
 
     if !output.status.success() {
         return Err(RezToolsError::ConfigError(format!(
-            "uv venv failed: {}",
+            "uv tool install rez failed: {}",
             String::from_utf8_lossy(&output.stderr)
         )));
     }
 
-    // Install rez in the virtual environment
-    let pip_path = get_venv_pip_path(&venv_path)?;
-    let output = AsyncCommand::new(&pip_path)
-        .args(["install", "rez"])
-        .output()
-        .await
-        .map_err(|e| RezToolsError::ConfigError(format!("Failed to install rez: {}", e)))?;
+    let tool_bin_dir = uv_tool_bin_dir(timeouts).await?;
+    let rez_exe = executable::find_executable("rez", &[tool_bin_dir])?;
+
+    info!("Installed rez as a uv tool at: {}", rez_exe.display());
+    Ok(rez_exe)
+}
+
+/// Ask uv where it materializes tool launcher scripts, via `uv tool dir --bin`
+async fn uv_tool_bin_dir(timeouts: CommandTimeouts) -> Result<PathBuf> {
+    let mut cmd = AsyncCommand::new("uv");
+    cmd.args(["tool", "dir", "--bin"]);
+    let output = run_with_timeout(cmd, timeouts.probe).await?;
+
+    if !output.status.success() {
+        return Err(RezToolsError::ConfigError(format!(
+            "uv tool dir --bin failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        )));
+    }
+
+    let dir = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if dir.is_empty() {
+        return Err(RezToolsError::ConfigError(
+            "uv tool dir --bin returned an empty path".to_string(),
+        ));
+    }
+
+    Ok(PathBuf::from(dir))
+}
+
+/// Try to install rez using uv. Reuses an already-activated or nearby
+/// virtual environment instead of creating a new `~/.rez-tools/venv` when
+/// one is discoverable (see `discover_venvs`); if that environment already
+/// satisfies rez, installation is skipped entirely. When a lockfile exists
+/// and `refresh_lock` is false, installs from its pinned versions instead
+/// of resolving `requirement` again; otherwise resolves normally and
+/// (re)writes the lockfile from the result.
+async fn try_install_with_uv(
+    requirement: &str,
+    refresh_lock: bool,
+    timeouts: CommandTimeouts,
+) -> Result<()> {
+    debug!("Attempting to install {} with uv", requirement);
+
+    // Check if uv is available
+    let mut uv_version_cmd = AsyncCommand::new("uv");
+    uv_version_cmd.arg("--version");
+    if let Err(e) = run_with_timeout(uv_version_cmd, timeouts.probe).await {
+        debug!("uv not found: {}", e);
+        return Err(RezToolsError::ConfigError("uv not found".to_string()));
+    }
+
+    if let Some(python_exe) = discover_venvs().into_iter().next() {
+        if venv_satisfies_rez(&python_exe, timeouts).await {
+            info!(
+                "rez is already installed in existing venv: {}",
+                python_exe.display()
+            );
+            create_rez_production_marker(&python_exe).await?;
+            create_rez_wrapper(&python_exe, timeouts).await?;
+            return Ok(());
+        }
+
+        debug!("Reusing existing venv as install target: {}", python_exe.display());
+        return install_rez_with_python_exe(&python_exe, requirement, refresh_lock, timeouts).await;
+    }
+
+    // No existing venv found nearby; create one under ~/.rez-tools
+    let venv_path = rez_tools_dir().join("venv");
+
+    let mut cmd = AsyncCommand::new("uv");
+    cmd.args(["venv", venv_path.to_string_lossy().as_ref()]);
+    let output = run_with_timeout(cmd, timeouts.install).await?;
 
     if !output.status.success() {
         return Err(RezToolsError::ConfigError(format!(
-            "pip install rez failed: {}",
+            "uv venv failed: {}",
             String::from_utf8_lossy(&output.stderr)
         )));
     }
@@ -81,18 +216,74 @@ async fn try_install_with_uv() -> Result<()> {
         venv_path.join("bin").join("python")
     };
 
-    // Create production install marker to avoid pip installation warnings
-    create_rez_production_marker(&python_exe).await?;
+    install_rez_with_python_exe(&python_exe, requirement, refresh_lock, timeouts).await
+}
 
-    // Create rez wrapper script
-    create_rez_wrapper(&python_exe).await?;
+/// Conventional markers of a Python virtual environment, checked in a
+/// directory and its ancestors
+const VENV_DIR_NAMES: &[&str] = &[".venv", "venv"];
+
+/// Maximum number of parent directories to walk when searching for a
+/// nearby virtual environment, so a search from deep inside a large repo
+/// doesn't wander all the way up to the filesystem root
+const VENV_SEARCH_MAX_ANCESTORS: usize = 5;
+
+/// Find usable virtual environments without creating one: an already
+/// activated env (`VIRTUAL_ENV`/`CONDA_DEFAULT_ENV`), then `.venv`/`venv`
+/// directories (identified by a `pyvenv.cfg` marker) in the current
+/// directory and up to `VENV_SEARCH_MAX_ANCESTORS` parent directories.
+/// Returns resolved interpreter paths, most likely match first.
+fn discover_venvs() -> Vec<PathBuf> {
+    let platform = Platform::detect();
+    let python_subpath = |venv_dir: &Path| -> PathBuf {
+        if platform.os == "windows" {
+            venv_dir.join("Scripts").join("python.exe")
+        } else {
+            venv_dir.join("bin").join("python")
+        }
+    };
 
-    Ok(())
+    let mut found = Vec::new();
+
+    for env_var in ["VIRTUAL_ENV", "CONDA_DEFAULT_ENV"] {
+        if let Ok(path) = std::env::var(env_var) {
+            let python_exe = python_subpath(Path::new(&path));
+            if python_exe.exists() {
+                found.push(python_exe);
+            }
+        }
+    }
+
+    if let Ok(cwd) = std::env::current_dir() {
+        for dir in cwd.ancestors().take(1 + VENV_SEARCH_MAX_ANCESTORS) {
+            for name in VENV_DIR_NAMES {
+                let venv_dir = dir.join(name);
+                if venv_dir.join("pyvenv.cfg").is_file() {
+                    let python_exe = python_subpath(&venv_dir);
+                    if python_exe.exists() {
+                        found.push(python_exe);
+                    }
+                }
+            }
+        }
+    }
+
+    found
+}
+
+/// Check whether `python_exe`'s environment already has rez installed
+async fn venv_satisfies_rez(python_exe: &Path, timeouts: CommandTimeouts) -> bool {
+    let mut cmd = AsyncCommand::new(python_exe);
+    cmd.args(["-m", "pip", "show", "rez"]);
+    run_with_timeout(cmd, timeouts.probe)
+        .await
+        .map(|output| output.status.success())
+        .unwrap_or(false)
 }
 
 /// Try to install rez using system pip
-async fn try_install_with_pip() -> Result<()> {
-    debug!("Attempting to install rez with pip");
+async fn try_install_with_pip(requirement: &str, timeouts: CommandTimeouts) -> Result<()> {
+    debug!("Attempting to install {} with pip", requirement);
 
     // Check if we're in a virtual environment
     let in_venv =
@@ -102,15 +293,15 @@ async fn try_install_with_pip() -> Result<()> {
     if !in_venv {
         args.push("--user");
     }
-    args.push("rez");
+    args.push(requirement);
 
-    let output = AsyncCommand::new("pip")
-        .args(&args)
-        .output()
+    let mut cmd = AsyncCommand::new("pip");
+    cmd.args(&args);
+    let output = run_with_timeout(cmd, timeouts.install)
         .await
         .map_err(|e| {
             debug!("Failed to run pip: {}", e);
-            RezToolsError::ConfigError(format!("Failed to run pip: {}", e))
+            e
         })?;
 
     if output.status.success() {
@@ -126,57 +317,125 @@ async fn try_install_with_pip() -> Result<()> {
 }
 
 /// Install Python Build Standalone and then rez
-async fn try_install_python_build_standalone() -> Result<()> {
+async fn try_install_python_build_standalone(
+    requirement: &str,
+    refresh_lock: bool,
+    python_version: Option<&str>,
+    timeouts: CommandTimeouts,
+) -> Result<()> {
+    install_rez_standalone_with_requirement(requirement, refresh_lock, python_version, timeouts)
+        .await
+        .map(|_| ())
+}
+
+/// Install rez into a Python provided by [`PythonStandalone`]: one already
+/// extracted under the managed prefix, else an existing system Python that
+/// satisfies the request, else a relocatable CPython downloaded from
+/// python-build-standalone (selected by OS, architecture and, on Linux,
+/// libc). rez is pip-installed into it and a wrapper script is written.
+/// Unlike `try_install_with_uv`/`_pip`, this needs no system Python, in the
+/// way pyoxidizer/uv ship embedded interpreters, but uses a matching one
+/// when present. Returns the rez-tools install root, whose `bin` directory
+/// holds the wrapper the caller can point `rez_path` at. `version_spec` optionally pins the
+/// install to a PEP 440 version specifier such as `==2.114.0`; `refresh_lock`
+/// controls whether an existing lockfile is installed from or re-resolved.
+/// `python_version` optionally pins the bundled release, parsed as a
+/// [`PythonRequest`] (e.g. `"3.11"`, `">=3.11,<3.13"`, `"pypy3.10"`); when
+/// `None`, a `.python-version`/`.python-versions` file discovered by
+/// walking up from the current directory is used instead, falling back to
+/// the newest stable CPython if none is found.
+pub async fn install_rez_standalone(
+    version_spec: Option<&str>,
+    refresh_lock: bool,
+    python_version: Option<&str>,
+    timeouts: CommandTimeouts,
+) -> Result<PathBuf> {
+    let requirement = build_requirement("rez", version_spec)?;
+    install_rez_standalone_with_requirement(&requirement, refresh_lock, python_version, timeouts)
+        .await
+}
+
+/// Same as `install_rez_standalone`, but pinned to a validated PEP 440
+/// requirement string such as `rez==2.114.0`
+async fn install_rez_standalone_with_requirement(
+    requirement: &str,
+    refresh_lock: bool,
+    python_version: Option<&str>,
+    timeouts: CommandTimeouts,
+) -> Result<PathBuf> {
     debug!("Attempting to install Python Build Standalone");
 
-    let install_dir = get_rez_tools_dir().join("python-build-standalone");
+    let rez_tools_dir = rez_tools_dir();
+    let install_dir = rez_tools_dir.join("python-build-standalone");
     let python_standalone = PythonStandalone::new(install_dir);
+    let python_request = match python_version {
+        Some(raw) => PythonRequest::parse(raw)?,
+        None => std::env::current_dir()
+            .ok()
+            .and_then(|cwd| PythonStandalone::resolve_request_from_dir(&cwd))
+            .unwrap_or(PythonRequest::Default),
+    };
 
     // Check if already installed
-    if python_standalone.is_installed().await {
+    let python_exe = if python_standalone.is_installed().await {
         info!("Python Build Standalone already installed");
-        let python_exe = python_standalone.get_python_executable()?;
-        return install_rez_with_python_exe(&python_exe).await;
-    }
+        python_standalone.get_python_executable()?
+    } else {
+        python_standalone.install(&python_request).await?
+    };
 
-    // Install Python Build Standalone
-    let python_exe = python_standalone.install().await?;
+    install_rez_with_python_exe(&python_exe, requirement, refresh_lock, timeouts).await?;
 
-    // Install rez using the standalone Python
-    install_rez_with_python_exe(&python_exe).await
+    Ok(rez_tools_dir)
 }
 
-/// Install rez using a specific Python executable
-async fn install_rez_with_python_exe(python_exe: &PathBuf) -> Result<()> {
-    info!("Installing rez using Python: {}", python_exe.display());
+/// Install rez using a specific Python executable. When a lockfile exists
+/// and `refresh_lock` is false, installs from its pinned versions instead
+/// of resolving `requirement` again; otherwise resolves normally and
+/// (re)writes the lockfile from the result.
+async fn install_rez_with_python_exe(
+    python_exe: &PathBuf,
+    requirement: &str,
+    refresh_lock: bool,
+    timeouts: CommandTimeouts,
+) -> Result<()> {
+    if !refresh_lock && lock::lock_file_path().exists() {
+        lock::install_rez_from_lock(python_exe, timeouts).await?;
+    } else {
+        info!(
+            "Installing {} using Python: {}",
+            requirement,
+            python_exe.display()
+        );
 
-    let output = AsyncCommand::new(python_exe)
-        .args(["-m", "pip", "install", "rez"])
-        .output()
-        .await
-        .map_err(|e| RezToolsError::ConfigError(format!("Failed to run pip: {}", e)))?;
+        let mut cmd = AsyncCommand::new(python_exe);
+        cmd.args(["-m", "pip", "install", requirement]);
+        let output = run_with_timeout(cmd, timeouts.install).await?;
 
-    if !output.status.success() {
-        return Err(RezToolsError::ConfigError(format!(
-            "Failed to install rez: {}",
-            String::from_utf8_lossy(&output.stderr)
-        )));
+        if !output.status.success() {
+            return Err(RezToolsError::ConfigError(format!(
+                "Failed to install rez: {}",
+                String::from_utf8_lossy(&output.stderr)
+            )));
+        }
+
+        lock::write_lock_file(python_exe, timeouts).await?;
     }
 
     // Create production install marker to avoid pip installation warnings
     create_rez_production_marker(python_exe).await?;
 
     // Create rez wrapper script
-    create_rez_wrapper(python_exe).await?;
+    create_rez_wrapper(python_exe, timeouts).await?;
 
     info!("Successfully installed rez");
     Ok(())
 }
 
 /// Create a rez wrapper script that uses our Python installation
-async fn create_rez_wrapper(python_exe: &Path) -> Result<()> {
+async fn create_rez_wrapper(python_exe: &Path, timeouts: CommandTimeouts) -> Result<()> {
     let platform = Platform::detect();
-    let rez_tools_dir = get_rez_tools_dir();
+    let rez_tools_dir = rez_tools_dir();
     let bin_dir = rez_tools_dir.join("bin");
 
     // Create bin directory
@@ -202,10 +461,9 @@ async fn create_rez_wrapper(python_exe: &Path) -> Result<()> {
 
     // Make executable on Unix systems
     if platform.os != "windows" {
-        let output = AsyncCommand::new("chmod")
-            .args(["+x", &wrapper_path.to_string_lossy()])
-            .output()
-            .await?;
+        let mut cmd = AsyncCommand::new("chmod");
+        cmd.args(["+x", &wrapper_path.to_string_lossy()]);
+        let output = run_with_timeout(cmd, timeouts.probe).await?;
 
         if !output.status.success() {
             warn!("Failed to make rez wrapper executable");
@@ -261,7 +519,7 @@ async fn create_rez_production_marker(python_exe: &Path) -> Result<()> {
 }
 
 /// Get rez-tools directory
-fn get_rez_tools_dir() -> PathBuf {
+pub(crate) fn rez_tools_dir() -> PathBuf {
     if let Some(home) = dirs::home_dir() {
         home.join(".rez-tools")
     } else {
@@ -269,24 +527,6 @@ fn get_rez_tools_dir() -> PathBuf {
     }
 }
 
-/// Get pip path in virtual environment
-fn get_venv_pip_path(venv_path: &Path) -> Result<PathBuf> {
-    let platform = Platform::detect();
-    let pip_path = if platform.os == "windows" {
-        venv_path.join("Scripts").join("pip.exe")
-    } else {
-        venv_path.join("bin").join("pip")
-    };
-
-    if pip_path.exists() {
-        Ok(pip_path)
-    } else {
-        Err(RezToolsError::ConfigError(
-            "pip not found in venv".to_string(),
-        ))
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -294,11 +534,39 @@ mod tests {
     use tempfile::TempDir;
 
     #[test]
-    fn test_get_rez_tools_dir() {
-        let dir = get_rez_tools_dir();
+    fn test_rez_tools_dir() {
+        let dir = rez_tools_dir();
         assert!(dir.to_string_lossy().contains(".rez-tools"));
     }
 
+    #[test]
+    fn test_build_requirement_no_spec() {
+        assert_eq!(build_requirement("rez", None).unwrap(), "rez");
+    }
+
+    #[test]
+    fn test_build_requirement_valid_specs() {
+        assert_eq!(
+            build_requirement("rez", Some("==2.114.0")).unwrap(),
+            "rez==2.114.0"
+        );
+        assert_eq!(
+            build_requirement("rez", Some(">=2.110,<3")).unwrap(),
+            "rez>=2.110,<3"
+        );
+        assert_eq!(
+            build_requirement("rez", Some("~=2.114")).unwrap(),
+            "rez~=2.114"
+        );
+    }
+
+    #[test]
+    fn test_build_requirement_rejects_malformed_spec() {
+        assert!(build_requirement("rez", Some("2.114.0")).is_err());
+        assert!(build_requirement("rez", Some("==")).is_err());
+        assert!(build_requirement("rez", Some("; rm -rf /")).is_err());
+    }
+
     #[tokio::test]
     async fn test_create_rez_production_marker() {
         let temp_dir = TempDir::new().unwrap();
@@ -354,53 +622,49 @@ mod tests {
     }
 
     #[test]
-    fn test_get_venv_pip_path_windows() {
+    fn test_discover_venvs_finds_dot_venv_in_cwd() {
         let temp_dir = TempDir::new().unwrap();
-        let venv_path = temp_dir.path().to_path_buf();
-        let scripts_dir = venv_path.join("Scripts");
-        let pip_exe = scripts_dir.join("pip.exe");
+        let venv_dir = temp_dir.path().join(".venv");
+        let bin_dir = if cfg!(windows) {
+            venv_dir.join("Scripts")
+        } else {
+            venv_dir.join("bin")
+        };
+        let python_name = if cfg!(windows) { "python.exe" } else { "python" };
 
-        // Create directory structure and pip executable
-        fs::create_dir_all(&scripts_dir).unwrap();
-        fs::write(&pip_exe, "fake pip").unwrap();
+        fs::create_dir_all(&bin_dir).unwrap();
+        fs::write(venv_dir.join("pyvenv.cfg"), "home = /usr").unwrap();
+        fs::write(bin_dir.join(python_name), "fake python").unwrap();
 
-        if cfg!(windows) {
-            let result = get_venv_pip_path(&venv_path);
-            assert!(result.is_ok());
-            assert_eq!(result.unwrap(), pip_exe);
-        }
+        let original_cwd = std::env::current_dir().unwrap();
+        std::env::set_current_dir(temp_dir.path()).unwrap();
+        let found = discover_venvs();
+        std::env::set_current_dir(original_cwd).unwrap();
+
+        assert!(found.contains(&bin_dir.join(python_name)));
     }
 
     #[test]
-    fn test_get_venv_pip_path_unix() {
+    fn test_discover_venvs_ignores_dir_without_pyvenv_cfg() {
         let temp_dir = TempDir::new().unwrap();
-        let venv_path = temp_dir.path().to_path_buf();
-        let bin_dir = venv_path.join("bin");
-        let pip_exe = bin_dir.join("pip");
+        let venv_dir = temp_dir.path().join(".venv");
+        fs::create_dir_all(venv_dir.join("bin")).unwrap();
+        fs::write(venv_dir.join("bin").join("python"), "fake python").unwrap();
+        // No pyvenv.cfg marker written
 
-        // Create directory structure and pip executable
-        fs::create_dir_all(&bin_dir).unwrap();
-        fs::write(&pip_exe, "fake pip").unwrap();
+        let original_cwd = std::env::current_dir().unwrap();
+        std::env::set_current_dir(temp_dir.path()).unwrap();
+        let found = discover_venvs();
+        std::env::set_current_dir(original_cwd).unwrap();
 
-        if !cfg!(windows) {
-            let result = get_venv_pip_path(&venv_path);
-            assert!(result.is_ok());
-            assert_eq!(result.unwrap(), pip_exe);
-        }
+        assert!(found.is_empty());
     }
 
-    #[test]
-    fn test_get_venv_pip_path_not_found() {
+    #[tokio::test]
+    async fn test_venv_satisfies_rez_false_for_missing_interpreter() {
         let temp_dir = TempDir::new().unwrap();
-        let venv_path = temp_dir.path().to_path_buf();
-
-        // Don't create pip executable
-        let result = get_venv_pip_path(&venv_path);
-        assert!(result.is_err());
-        assert!(result
-            .unwrap_err()
-            .to_string()
-            .contains("pip not found in venv"));
+        let bogus_python = temp_dir.path().join("nonexistent-python");
+        assert!(!venv_satisfies_rez(&bogus_python, CommandTimeouts::default()).await);
     }
 
     #[test]
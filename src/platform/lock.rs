@@ -0,0 +1,90 @@
+//! Lockfile support for reproducible rez installs.
+//!
+//! Every install otherwise resolves whatever rez/transitive versions happen
+//! to be current, so two machines provisioned days apart can diverge. This
+//! module captures the fully-resolved dependency set of a managed Python
+//! environment via `pip freeze` and writes it to a lockfile, then lets a
+//! later install consume that lockfile's pinned `==` versions instead of
+//! resolving from scratch — mirroring how modern Python package managers
+//! separate "resolve" from "sync" to guarantee byte-identical environments.
+
+use crate::error::{Result, RezToolsError};
+use crate::platform::installer::rez_tools_dir;
+use crate::platform::timeout::{run_with_timeout, CommandTimeouts};
+use log::{debug, info};
+use std::path::{Path, PathBuf};
+use tokio::fs;
+use tokio::process::Command as AsyncCommand;
+
+/// Path to the rez-tools lockfile
+pub fn lock_file_path() -> PathBuf {
+    rez_tools_dir().join("rez.lock")
+}
+
+/// Capture the fully-resolved dependency set of `python_exe`'s environment
+/// via `pip freeze` and write it to the lockfile
+pub async fn write_lock_file(python_exe: &Path, timeouts: CommandTimeouts) -> Result<PathBuf> {
+    debug!(
+        "Freezing dependencies for lockfile via {}",
+        python_exe.display()
+    );
+
+    let mut cmd = AsyncCommand::new(python_exe);
+    cmd.args(["-m", "pip", "freeze"]);
+    let output = run_with_timeout(cmd, timeouts.install).await?;
+
+    if !output.status.success() {
+        return Err(RezToolsError::ConfigError(format!(
+            "pip freeze failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        )));
+    }
+
+    let path = lock_file_path();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).await?;
+    }
+    fs::write(&path, output.stdout).await?;
+
+    info!("Wrote rez-tools lockfile: {}", path.display());
+    Ok(path)
+}
+
+/// Install into `python_exe`'s environment from the existing lockfile's
+/// pinned `==` versions, instead of an unpinned resolve
+pub async fn install_rez_from_lock(python_exe: &Path, timeouts: CommandTimeouts) -> Result<()> {
+    let path = lock_file_path();
+    if !path.exists() {
+        return Err(RezToolsError::ConfigError(format!(
+            "No lockfile found at {} (pass --refresh-lock to create one)",
+            path.display()
+        )));
+    }
+
+    debug!("Installing from lockfile: {}", path.display());
+
+    let mut cmd = AsyncCommand::new(python_exe);
+    cmd.args(["-m", "pip", "install", "-r"]).arg(&path);
+    let output = run_with_timeout(cmd, timeouts.install).await?;
+
+    if !output.status.success() {
+        return Err(RezToolsError::ConfigError(format!(
+            "pip install -r {} failed: {}",
+            path.display(),
+            String::from_utf8_lossy(&output.stderr)
+        )));
+    }
+
+    info!("Installed from lockfile: {}", path.display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lock_file_path_is_named_rez_lock() {
+        assert_eq!(lock_file_path().file_name().unwrap(), "rez.lock");
+    }
+}
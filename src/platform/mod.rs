@@ -1,9 +1,14 @@
 pub mod detection;
+pub mod doctor;
 pub mod download;
+pub mod executable;
 pub mod extract;
 pub mod installer;
+pub mod lock;
 pub mod python_standalone;
 pub mod rez_path;
+pub mod shim;
+pub mod timeout;
 
 use crate::error::{Result, RezToolsError};
 use std::path::PathBuf;
@@ -75,38 +80,43 @@ impl RezEnvironment {
         detection::detect_rez_environment()
     }
 
-    /// Install rez if not present
-    pub async fn ensure_installed(&mut self) -> Result<()> {
+    /// Install rez if not present, using `timeouts` for the install's
+    /// external command invocations (see [`timeout::CommandTimeouts`])
+    pub async fn ensure_installed(&mut self, timeouts: timeout::CommandTimeouts) -> Result<()> {
         if !self.is_installed {
-            installer::install_rez().await?;
+            installer::install_rez(None, false, timeouts).await?;
             *self = Self::detect()?;
         }
         Ok(())
     }
 
     /// Get rez command path
+    ///
+    /// Resolution order: an explicit `REZ_EXECUTABLE` override, the `bin`
+    /// directory of the configured rez install, then every directory on
+    /// `PATH` (trying each `PATHEXT` suffix on Windows). Each candidate is
+    /// confirmed runnable via `rez --version` before being accepted, which
+    /// is what makes this work on Windows (no `which`) and avoids
+    /// accepting a stale or broken `rez` shim.
     pub fn rez_command(&self) -> Result<PathBuf> {
-        if let Some(ref rez_path) = self.rez_path {
-            let platform = Platform::detect();
-            let rez_exe = rez_path
-                .join("bin")
-                .join(format!("rez{}", platform.exe_extension()));
-            if rez_exe.exists() {
-                return Ok(rez_exe);
+        if let Ok(exe_override) = std::env::var("REZ_EXECUTABLE") {
+            let path = PathBuf::from(exe_override);
+            if path.is_file() && executable::is_runnable(&path) {
+                return Ok(path);
             }
         }
 
-        // Try to find rez in PATH
-        if let Ok(output) = std::process::Command::new("which").arg("rez").output() {
-            if output.status.success() {
-                let output_str = String::from_utf8_lossy(&output.stdout);
-                let path_str = output_str.trim();
-                return Ok(PathBuf::from(path_str));
-            }
-        }
+        let extra_dirs = self
+            .rez_path
+            .as_ref()
+            .map(|rez_path| vec![rez_path.join("bin")])
+            .unwrap_or_default();
 
-        Err(RezToolsError::ConfigError(
-            "Rez command not found. Please install rez or run 'rt install-rez'".to_string(),
-        ))
+        executable::find_executable("rez", &extra_dirs).map_err(|e| {
+            RezToolsError::ConfigError(format!(
+                "Rez command not found (run 'rt install-rez' to install it): {}",
+                e
+            ))
+        })
     }
 }
@@ -1,60 +1,678 @@
 use crate::error::{Result, RezToolsError};
-use crate::platform::{download::DownloadClient, extract::Extractor, Platform};
+use crate::platform::{download::DownloadClient, extract::extract_archive, Platform};
 use log::{debug, info};
+use serde::Deserialize;
 use serde_json::Value;
-use std::path::PathBuf;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::{Mutex, OnceLock};
+use std::time::SystemTime;
 use tokio::fs;
 
+/// Structured metadata about a Python interpreter, gathered by
+/// `PythonStandalone::query_interpreter`
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct InterpreterInfo {
+    /// (major, minor, micro) from `sys.version_info`
+    pub version: (u32, u32, u32),
+    /// `sys.implementation.name`, e.g. "cpython"
+    pub implementation_name: String,
+    /// `platform.machine()`, e.g. "x86_64"
+    pub machine: String,
+    /// `sys.prefix`
+    pub prefix: PathBuf,
+    /// `sys.base_prefix` (differs from `prefix` inside a venv)
+    pub base_prefix: PathBuf,
+    /// `struct.calcsize("P") * 8`, i.e. 32 or 64
+    pub pointer_width: u32,
+    /// `sysconfig.get_config_var("SOABI")`, e.g. "cpython-311-x86_64-linux-gnu"
+    pub abi_tag: Option<String>,
+    /// `sysconfig.get_platform()`, e.g. "linux-x86_64"
+    pub platform_tag: String,
+    /// `sysconfig.get_path("stdlib")`
+    pub stdlib_path: PathBuf,
+    /// `sysconfig.get_path("purelib")`
+    pub purelib_path: PathBuf,
+    /// `sysconfig.get_path("scripts")`
+    pub scripts_path: PathBuf,
+    /// The real `sys.executable`, resolving symlinks/wrappers
+    pub executable: PathBuf,
+    /// Whether `import rez` succeeds in this interpreter
+    pub rez_importable: bool,
+    /// `rez.__version__`, if `rez` is importable
+    pub rez_version: Option<String>,
+}
+
+/// Cache of interpreter probes, keyed by canonicalized path and mtime so a
+/// rebuilt/replaced interpreter at the same path is re-probed.
+static INTERPRETER_CACHE: OnceLock<Mutex<HashMap<(PathBuf, SystemTime), InterpreterInfo>>> =
+    OnceLock::new();
+
+/// Run the probe script and parse its JSON output
+fn probe_interpreter(python_exe: &Path) -> Result<InterpreterInfo> {
+    const PROBE_SCRIPT: &str = r#"
+import json, struct, sys, platform, sysconfig
+
+try:
+    import rez
+    rez_importable = True
+    rez_version = getattr(rez, "__version__", None)
+except ImportError:
+    rez_importable = False
+    rez_version = None
+
+print(json.dumps({
+    "version": list(sys.version_info[:3]),
+    "implementation_name": sys.implementation.name,
+    "machine": platform.machine(),
+    "prefix": sys.prefix,
+    "base_prefix": sys.base_prefix,
+    "pointer_width": struct.calcsize("P") * 8,
+    "abi_tag": sysconfig.get_config_var("SOABI"),
+    "platform_tag": sysconfig.get_platform(),
+    "stdlib_path": sysconfig.get_path("stdlib"),
+    "purelib_path": sysconfig.get_path("purelib"),
+    "scripts_path": sysconfig.get_path("scripts"),
+    "executable": sys.executable,
+    "rez_importable": rez_importable,
+    "rez_version": rez_version,
+}))
+"#;
+
+    let output = Command::new(python_exe)
+        .args(["-c", PROBE_SCRIPT])
+        .output()
+        .map_err(|e| RezToolsError::ConfigError(format!("Failed to run interpreter probe: {}", e)))?;
+
+    if !output.status.success() {
+        return Err(RezToolsError::ConfigError(format!(
+            "Interpreter probe failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        )));
+    }
+
+    #[derive(Deserialize)]
+    struct RawProbe {
+        version: [u32; 3],
+        implementation_name: String,
+        machine: String,
+        prefix: PathBuf,
+        base_prefix: PathBuf,
+        pointer_width: u32,
+        abi_tag: Option<String>,
+        platform_tag: String,
+        stdlib_path: PathBuf,
+        purelib_path: PathBuf,
+        scripts_path: PathBuf,
+        executable: PathBuf,
+        rez_importable: bool,
+        rez_version: Option<String>,
+    }
+
+    let raw: RawProbe = serde_json::from_slice(&output.stdout)
+        .map_err(|e| RezToolsError::ConfigError(format!("Invalid probe output: {}", e)))?;
+
+    Ok(InterpreterInfo {
+        version: (raw.version[0], raw.version[1], raw.version[2]),
+        implementation_name: raw.implementation_name,
+        machine: raw.machine,
+        prefix: raw.prefix,
+        base_prefix: raw.base_prefix,
+        pointer_width: raw.pointer_width,
+        abi_tag: raw.abi_tag,
+        platform_tag: raw.platform_tag,
+        stdlib_path: raw.stdlib_path,
+        purelib_path: raw.purelib_path,
+        scripts_path: raw.scripts_path,
+        executable: raw.executable,
+        rez_importable: raw.rez_importable,
+        rez_version: raw.rez_version,
+    })
+}
+
+/// Which Python implementation an asset provides. python-build-standalone
+/// only publishes CPython today, but the grammar accepts `pypy...` requests
+/// in anticipation of that changing, mirroring uv's `ImplementationName`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PythonImplementation {
+    CPython,
+    PyPy,
+}
+
+impl PythonImplementation {
+    fn parse(name: &str) -> Option<Self> {
+        match name {
+            "cpython" => Some(PythonImplementation::CPython),
+            "pypy" => Some(PythonImplementation::PyPy),
+            _ => None,
+        }
+    }
+}
+
+/// A single version comparison, e.g. the `>=3.11` half of `>=3.11,<3.13`.
+/// Components left unset by the request (e.g. no patch in `3.11`) are
+/// treated as wildcards for `Eq` and filled in from the candidate for the
+/// ordered operators, so `>=3.11` matches `3.11.0` and everything after it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum VersionOp {
+    Eq,
+    Ge,
+    Gt,
+    Le,
+    Lt,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct VersionClause {
+    op: VersionOp,
+    major: u32,
+    minor: Option<u32>,
+    patch: Option<u32>,
+}
+
+impl VersionClause {
+    fn parse(raw: &str) -> Result<Self> {
+        let invalid = || {
+            RezToolsError::ConfigError(format!("Invalid Python version request '{}'", raw))
+        };
+
+        let (op, rest) = if let Some(r) = raw.strip_prefix(">=") {
+            (VersionOp::Ge, r)
+        } else if let Some(r) = raw.strip_prefix("<=") {
+            (VersionOp::Le, r)
+        } else if let Some(r) = raw.strip_prefix("==") {
+            (VersionOp::Eq, r)
+        } else if let Some(r) = raw.strip_prefix('>') {
+            (VersionOp::Gt, r)
+        } else if let Some(r) = raw.strip_prefix('<') {
+            (VersionOp::Lt, r)
+        } else {
+            (VersionOp::Eq, raw)
+        };
+
+        let mut segments = rest.trim().split('.');
+        let major = segments
+            .next()
+            .filter(|s| !s.is_empty())
+            .ok_or_else(invalid)?
+            .parse::<u32>()
+            .map_err(|_| invalid())?;
+        let minor = segments
+            .next()
+            .map(|s| s.parse::<u32>().map_err(|_| invalid()))
+            .transpose()?;
+        let patch = segments
+            .next()
+            .map(|s| s.parse::<u32>().map_err(|_| invalid()))
+            .transpose()?;
+
+        Ok(VersionClause { op, major, minor, patch })
+    }
+
+    fn matches(&self, major: u32, minor: u32, patch: u32) -> bool {
+        match self.op {
+            VersionOp::Eq => {
+                self.major == major
+                    && self.minor.map_or(true, |m| m == minor)
+                    && self.patch.map_or(true, |p| p == patch)
+            }
+            _ => {
+                let requested = (self.major, self.minor.unwrap_or(minor), self.patch.unwrap_or(patch));
+                let candidate = (major, minor, patch);
+                match self.op {
+                    VersionOp::Ge => candidate >= requested,
+                    VersionOp::Gt => candidate > requested,
+                    VersionOp::Le => candidate <= requested,
+                    VersionOp::Lt => candidate < requested,
+                    VersionOp::Eq => unreachable!(),
+                }
+            }
+        }
+    }
+}
+
+/// A comma-separated list of `VersionClause`s, all of which must hold, e.g.
+/// `">=3.11,<3.13"`. An empty constraint (no clauses) matches any version.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+struct VersionConstraint {
+    clauses: Vec<VersionClause>,
+}
+
+impl VersionConstraint {
+    fn parse(raw: &str) -> Result<Self> {
+        let clauses = raw
+            .split(',')
+            .map(|part| VersionClause::parse(part.trim()))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(VersionConstraint { clauses })
+    }
+
+    fn matches(&self, major: u32, minor: u32, patch: u32) -> bool {
+        self.clauses.iter().all(|clause| clause.matches(major, minor, patch))
+    }
+}
+
+/// Which Python interpreter to install, parsed from strings like `"3.10"`,
+/// `"3.12.1"`, `">=3.11,<3.13"`, `"cpython"`, `"pypy3.10"`, or `"any"`. This
+/// mirrors uv's `PythonRequest`: a bare [`PythonRequest::Default`] keeps
+/// today's behavior of picking the newest stable CPython release, while
+/// [`PythonRequest::Any`] additionally permits alternative implementations.
+/// Pre-release assets (e.g. `3.14.0a1`) match no request, since their
+/// version does not parse as `major.minor.patch`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum PythonRequest {
+    /// No constraint given: the newest stable CPython release.
+    #[default]
+    Default,
+    /// Accept any implementation and any stable version.
+    Any,
+    /// A specific implementation and/or version constraint.
+    Version {
+        implementation: Option<PythonImplementation>,
+        constraint: VersionConstraint,
+    },
+}
+
+impl PythonRequest {
+    /// Parse a request string as accepted by `--python-version`. An empty
+    /// string is equivalent to [`PythonRequest::Default`].
+    pub fn parse(raw: &str) -> Result<Self> {
+        let raw = raw.trim();
+        if raw.is_empty() {
+            return Ok(PythonRequest::Default);
+        }
+        if raw.eq_ignore_ascii_case("any") {
+            return Ok(PythonRequest::Any);
+        }
+
+        let (implementation, version_part) = if let Some(rest) = raw.strip_prefix("pypy") {
+            (Some(PythonImplementation::PyPy), rest)
+        } else if let Some(rest) = raw.strip_prefix("cpython") {
+            (Some(PythonImplementation::CPython), rest)
+        } else {
+            (None, raw)
+        };
+
+        let constraint = if version_part.is_empty() {
+            VersionConstraint::default()
+        } else {
+            VersionConstraint::parse(version_part)?
+        };
+
+        Ok(PythonRequest::Version { implementation, constraint })
+    }
+
+    /// Whether `asset` satisfies this request.
+    fn matches(&self, asset: &AssetInfo) -> bool {
+        match self {
+            PythonRequest::Default => asset.implementation == PythonImplementation::CPython,
+            PythonRequest::Any => true,
+            PythonRequest::Version { implementation, constraint } => {
+                implementation.map_or(true, |want| want == asset.implementation)
+                    && constraint.matches(asset.major, asset.minor, asset.patch)
+            }
+        }
+    }
+
+    /// Whether a probed, already-installed interpreter satisfies this
+    /// request. Used to validate an extraction actually produced what was
+    /// asked for, and to filter system Python candidates in
+    /// `find_system_python`.
+    fn matches_interpreter(&self, info: &InterpreterInfo) -> bool {
+        let (major, minor, patch) = info.version;
+        match self {
+            PythonRequest::Default => info.implementation_name.eq_ignore_ascii_case("cpython"),
+            PythonRequest::Any => true,
+            PythonRequest::Version { implementation, constraint } => {
+                implementation.map_or(true, |want| {
+                    info.implementation_name.eq_ignore_ascii_case(&want.to_string())
+                }) && constraint.matches(major, minor, patch)
+            }
+        }
+    }
+
+    /// Human-readable description for error messages.
+    fn describe(&self) -> String {
+        match self {
+            PythonRequest::Default => String::new(),
+            PythonRequest::Any => " (any)".to_string(),
+            PythonRequest::Version { .. } => format!(" matching request '{}'", self),
+        }
+    }
+}
+
+impl std::fmt::Display for PythonRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PythonRequest::Default => write!(f, "default"),
+            PythonRequest::Any => write!(f, "any"),
+            PythonRequest::Version { implementation, constraint } => {
+                if let Some(implementation) = implementation {
+                    write!(f, "{} ", implementation)?;
+                }
+                if constraint.clauses.is_empty() {
+                    write!(f, "any version")
+                } else {
+                    let rendered: Vec<String> =
+                        constraint.clauses.iter().map(|c| c.to_string()).collect();
+                    write!(f, "{}", rendered.join(","))
+                }
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for PythonImplementation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PythonImplementation::CPython => write!(f, "cpython"),
+            PythonImplementation::PyPy => write!(f, "pypy"),
+        }
+    }
+}
+
+impl std::fmt::Display for VersionClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let op = match self.op {
+            VersionOp::Eq => "==",
+            VersionOp::Ge => ">=",
+            VersionOp::Gt => ">",
+            VersionOp::Le => "<=",
+            VersionOp::Lt => "<",
+        };
+        write!(f, "{}{}", op, self.major)?;
+        if let Some(minor) = self.minor {
+            write!(f, ".{}", minor)?;
+        }
+        if let Some(patch) = self.patch {
+            write!(f, ".{}", patch)?;
+        }
+        Ok(())
+    }
+}
+
+/// The (implementation, version, platform, flavor) identity of a
+/// python-build-standalone release asset, parsed out of its filename so
+/// `find_best_asset` can match and sort candidates structurally instead of
+/// doing substring checks against a fixed priority list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct AssetInfo {
+    implementation: PythonImplementation,
+    major: u32,
+    minor: u32,
+    patch: u32,
+    platform_triple: String,
+    flavor: String,
+    is_tar_zst: bool,
+}
+
+/// Archive flavors python-build-standalone publishes, matched against the
+/// end of an asset's name.
+const KNOWN_ASSET_FLAVORS: [&str; 2] = ["install_only_stripped", "install_only"];
+
+/// Parse an asset filename like
+/// `cpython-3.11.9+20240415-x86_64-unknown-linux-gnu-install_only.tar.gz`
+/// into its `AssetInfo`. Returns `None` for names that don't match this
+/// shape (e.g. the `.sha256`/`SHA256SUMS` checksum manifests).
+fn parse_asset_name(name: &str) -> Option<AssetInfo> {
+    let (stem, is_tar_zst) = if let Some(stem) = name.strip_suffix(".tar.zst") {
+        (stem, true)
+    } else if let Some(stem) = name.strip_suffix(".tar.gz") {
+        (stem, false)
+    } else {
+        return None;
+    };
+
+    let (impl_str, rest) = stem.split_once('-')?;
+    let implementation = PythonImplementation::parse(impl_str)?;
+
+    let (version_and_build, rest) = rest.split_once('-')?;
+    let version = version_and_build.split('+').next().unwrap_or(version_and_build);
+    let mut version_parts = version.splitn(3, '.');
+    let major = version_parts.next()?.parse().ok()?;
+    let minor = version_parts.next()?.parse().ok()?;
+    let patch = version_parts.next().unwrap_or("0").parse().ok()?;
+
+    let flavor = *KNOWN_ASSET_FLAVORS.iter().find(|f| rest.ends_with(*f))?;
+    let platform_triple = rest
+        .trim_end_matches(flavor)
+        .trim_end_matches('-')
+        .to_string();
+
+    Some(AssetInfo {
+        implementation,
+        major,
+        minor,
+        patch,
+        platform_triple,
+        flavor: flavor.to_string(),
+        is_tar_zst,
+    })
+}
+
+/// Pins the release tag (e.g. `"20240415"`) used in place of
+/// `releases/latest`, for reproducible builds against a known-good
+/// python-build-standalone release.
+const PYTHON_RELEASE_ENV: &str = "REZ_TOOLS_PYTHON_RELEASE";
+
+/// Points at a mirror serving the same python-build-standalone release
+/// layout as GitHub, for CI that can reach an internal mirror but not
+/// `github.com`. Asset URLs are rewritten to keep this mirror's
+/// `<release-tag>/<filename>` suffix, the same way uv's
+/// `UV_PYTHON_INSTALL_MIRROR` does.
+const PYTHON_MIRROR_ENV: &str = "REZ_TOOLS_PYTHON_MIRROR";
+
+/// Points at a local directory of vendored release archives, checked before
+/// any network access, mirroring uv's `UV_BOOTSTRAP_DIR`. Lets teams install
+/// fully offline once a matching archive has been placed there.
+const PYTHON_BOOTSTRAP_DIR_ENV: &str = "REZ_TOOLS_PYTHON_BOOTSTRAP_DIR";
+
 /// Python Build Standalone manager
 pub struct PythonStandalone {
     download_client: DownloadClient,
     install_dir: PathBuf,
+    /// Pinned release tag from `REZ_TOOLS_PYTHON_RELEASE`, if set.
+    release_tag: Option<String>,
+    /// Mirror base URL from `REZ_TOOLS_PYTHON_MIRROR`, if set.
+    mirror_base_url: Option<String>,
+    /// Local archive directory from `REZ_TOOLS_PYTHON_BOOTSTRAP_DIR`, if set.
+    bootstrap_dir: Option<PathBuf>,
 }
 
 impl PythonStandalone {
-    /// Create a new Python Build Standalone manager
+    /// Create a new Python Build Standalone manager. Reads
+    /// `REZ_TOOLS_PYTHON_RELEASE`, `REZ_TOOLS_PYTHON_MIRROR`, and
+    /// `REZ_TOOLS_PYTHON_BOOTSTRAP_DIR` so offline/air-gapped installs only
+    /// require setting environment variables, not plumbing new arguments
+    /// through every caller.
     pub fn new(install_dir: PathBuf) -> Self {
         Self {
             download_client: DownloadClient::new(),
             install_dir,
+            release_tag: std::env::var(PYTHON_RELEASE_ENV)
+                .ok()
+                .filter(|s| !s.is_empty()),
+            mirror_base_url: std::env::var(PYTHON_MIRROR_ENV)
+                .ok()
+                .filter(|s| !s.is_empty())
+                .map(|s| s.trim_end_matches('/').to_string()),
+            bootstrap_dir: std::env::var_os(PYTHON_BOOTSTRAP_DIR_ENV).map(PathBuf::from),
+        }
+    }
+
+    /// Probe `python_exe`, returning structured interpreter metadata
+    /// (version, implementation, `sys.prefix`/`base_prefix`, pointer width,
+    /// and ABI/platform tags) so a caller can validate the interpreter it
+    /// found is actually runnable and architecture/ABI-compatible, instead
+    /// of trusting a bare `PathBuf`.
+    ///
+    /// Results are cached by the interpreter's canonicalized path and mtime,
+    /// so repeated detection runs against the same interpreter don't
+    /// re-spawn Python.
+    pub fn query_interpreter(python_exe: &Path) -> Result<InterpreterInfo> {
+        let canonical_path = python_exe
+            .canonicalize()
+            .unwrap_or_else(|_| python_exe.to_path_buf());
+        let mtime = std::fs::metadata(&canonical_path)
+            .and_then(|m| m.modified())
+            .unwrap_or(SystemTime::UNIX_EPOCH);
+        let cache_key = (canonical_path.clone(), mtime);
+
+        let cache = INTERPRETER_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+        if let Some(info) = cache.lock().unwrap().get(&cache_key) {
+            debug!("Using cached interpreter info for {}", canonical_path.display());
+            return Ok(info.clone());
+        }
+
+        let info = probe_interpreter(python_exe)?;
+        cache.lock().unwrap().insert(cache_key, info.clone());
+        Ok(info)
+    }
+
+    /// Discover a `.python-version` (or `.python-versions`) pin by walking
+    /// upward from `start_dir` to the filesystem root, the same way uv
+    /// resolves per-project Python pins. The first non-empty, non-comment
+    /// (`#`-prefixed) line of the first matching file is parsed as a
+    /// [`PythonRequest`]. Returns `None` if no pin file is found or its first
+    /// line fails to parse, so callers fall back to their own default.
+    pub fn resolve_request_from_dir(start_dir: &Path) -> Option<PythonRequest> {
+        for dir in start_dir.ancestors() {
+            for name in [".python-version", ".python-versions"] {
+                let Ok(contents) = std::fs::read_to_string(dir.join(name)) else {
+                    continue;
+                };
+                let Some(pin) = contents
+                    .lines()
+                    .map(str::trim)
+                    .find(|line| !line.is_empty() && !line.starts_with('#'))
+                else {
+                    continue;
+                };
+                return PythonRequest::parse(pin).ok();
+            }
         }
+        None
     }
 
-    /// Install Python Build Standalone for the current platform
-    pub async fn install(&self) -> Result<PathBuf> {
+    /// Install Python satisfying `request` (see [`PythonRequest`]), or the
+    /// newest stable CPython for [`PythonRequest::Default`]. An existing
+    /// system interpreter satisfying the request is used as-is, skipping the
+    /// download entirely; only when none is found does this fetch a
+    /// relocatable build from python-build-standalone. The download is
+    /// verified against the SHA-256 digest published in the release's
+    /// checksum manifest before extraction, so a tampered or corrupted
+    /// distribution is rejected rather than silently unpacked.
+    pub async fn install(&self, request: &PythonRequest) -> Result<PathBuf> {
         info!("Installing Python Build Standalone...");
 
+        if let Some(system_python) = self.find_system_python(request) {
+            info!(
+                "Found existing system Python satisfying request: {}",
+                system_python.display()
+            );
+            return Ok(system_python);
+        }
+
         let platform = Platform::detect();
+        let target_pattern = self.get_target_pattern(&platform)?;
+
+        if let Some(bootstrap_dir) = &self.bootstrap_dir {
+            if let Some(archive_path) =
+                self.find_local_bootstrap_archive(bootstrap_dir, &target_pattern, request)
+            {
+                info!(
+                    "Using vendored Python archive from bootstrap dir: {}",
+                    archive_path.display()
+                );
+                return self.install_from_archive(&archive_path, &platform, request).await;
+            }
+        }
 
-        // Get the download URL and filename
-        let (download_url, filename) = self.get_download_info(&platform).await?;
+        // Get the download URL, filename and expected checksum
+        let (download_url, filename, expected_sha256) =
+            self.get_download_info(&platform, request).await?;
 
-        // Download the archive
+        // Download the archive, verifying its digest as it streams to disk
         let archive_path = self.install_dir.join(&filename);
-        self.download_client.download_file(&download_url, &archive_path).await?;
+        self.download_client
+            .download_file_verified(&download_url, &archive_path, &expected_sha256)
+            .await?;
 
-        // Extract the archive
-        let extract_dir = self.install_dir.join("python");
-        Extractor::extract(&archive_path, &extract_dir).await?;
+        let python_exe = self.install_from_archive(&archive_path, &platform, request).await?;
 
         // Clean up the archive
         fs::remove_file(&archive_path).await?;
 
+        Ok(python_exe)
+    }
+
+    /// Extract `archive_path` and validate the resulting interpreter against
+    /// `request`, shared by both the network download path and the
+    /// `REZ_TOOLS_PYTHON_BOOTSTRAP_DIR` offline path. Leaves `archive_path`
+    /// untouched; removing a downloaded copy afterward is the caller's job,
+    /// while a vendored bootstrap archive is left in place.
+    async fn install_from_archive(
+        &self,
+        archive_path: &Path,
+        platform: &Platform,
+        request: &PythonRequest,
+    ) -> Result<PathBuf> {
+        // Extract the archive, stripping the single top-level "python/" directory
+        // these releases ship so the interpreter lands at a predictable path.
+        // `extract_archive` decompresses and untars in a single streaming pass,
+        // so the distribution is never buffered whole in memory.
+        let extract_dir = self.install_dir.join("python");
+        extract_archive(archive_path, &extract_dir, true).await?;
+
         // Find the Python executable
-        let python_exe = self.find_python_executable(&extract_dir, &platform)?;
+        let python_exe = self.find_python_executable(&extract_dir, platform)?;
+
+        // Launch the freshly extracted interpreter and confirm it actually
+        // runs and matches what was requested, so a truncated extraction or
+        // a mismatched asset is caught here rather than surfacing later as a
+        // confusing failure downstream.
+        let info = Self::query_interpreter(&python_exe)?;
+        if !request.matches_interpreter(&info) {
+            return Err(RezToolsError::ConfigError(format!(
+                "Extracted interpreter {} ({} {}.{}.{}) does not satisfy request '{}'",
+                python_exe.display(),
+                info.implementation_name,
+                info.version.0,
+                info.version.1,
+                info.version.2,
+                request
+            )));
+        }
 
         info!("Python Build Standalone installed at: {}", python_exe.display());
         Ok(python_exe)
     }
 
-    /// Get download information for the current platform
-    async fn get_download_info(&self, platform: &Platform) -> Result<(String, String)> {
+    /// Get download information for the current platform: the asset's
+    /// download URL, filename, and expected SHA-256 digest from the
+    /// release's checksum manifest
+    async fn get_download_info(
+        &self,
+        platform: &Platform,
+        request: &PythonRequest,
+    ) -> Result<(String, String, String)> {
         info!("Fetching Python Build Standalone release information...");
 
-        // Get latest release info from GitHub API
-        let api_url = "https://api.github.com/repos/astral-sh/python-build-standalone/releases/latest";
+        // Get release info from GitHub API: the pinned tag from
+        // REZ_TOOLS_PYTHON_RELEASE if set, otherwise the latest release.
+        let api_url = match &self.release_tag {
+            Some(tag) => format!(
+                "https://api.github.com/repos/astral-sh/python-build-standalone/releases/tags/{}",
+                tag
+            ),
+            None => {
+                "https://api.github.com/repos/astral-sh/python-build-standalone/releases/latest"
+                    .to_string()
+            }
+        };
         let release_info: Value = self.download_client
-            .download_bytes(api_url)
+            .download_bytes(&api_url)
             .await
             .and_then(|bytes| {
                 serde_json::from_slice(&bytes)
@@ -67,57 +685,306 @@ impl PythonStandalone {
             .ok_or_else(|| RezToolsError::ConfigError("No assets found in release".to_string()))?;
 
         // Look for the best matching asset
-        let (download_url, filename) = self.find_best_asset(assets, &target_pattern)?;
+        let (download_url, filename) =
+            self.find_best_asset(assets, &target_pattern, request)?;
+        let download_url = self.rewrite_for_mirror(&download_url);
 
         info!("Selected Python distribution: {}", filename);
         debug!("Download URL: {}", download_url);
 
-        Ok((download_url, filename))
+        let expected_sha256 = self.fetch_expected_sha256(assets, &filename).await?;
+
+        Ok((download_url, filename, expected_sha256))
+    }
+
+    /// Rewrite a `browser_download_url` from GitHub to `REZ_TOOLS_PYTHON_MIRROR`
+    /// when set, keeping the `<release-tag>/<filename>` suffix the same way
+    /// uv's `UV_PYTHON_INSTALL_MIRROR` does, so a mirror only needs to serve
+    /// the release assets themselves, not replicate GitHub's full path shape.
+    fn rewrite_for_mirror(&self, download_url: &str) -> String {
+        let Some(mirror) = &self.mirror_base_url else {
+            return download_url.to_string();
+        };
+        match download_url.rsplit_once("/releases/download/") {
+            Some((_, suffix)) => format!("{}/{}", mirror, suffix),
+            None => download_url.to_string(),
+        }
+    }
+
+    /// Look up `filename`'s expected SHA-256 digest from the release's
+    /// checksum manifest, published either as a per-asset `<filename>.sha256`
+    /// file or a combined `SHA256SUMS` file listing every asset
+    async fn fetch_expected_sha256(&self, assets: &[Value], filename: &str) -> Result<String> {
+        let sha256_asset_name = format!("{}.sha256", filename);
+
+        for asset in assets {
+            let asset_name = asset["name"].as_str().unwrap_or("");
+            if !asset_name.eq_ignore_ascii_case(&sha256_asset_name)
+                && !asset_name.eq_ignore_ascii_case("SHA256SUMS")
+            {
+                continue;
+            }
+
+            let download_url = asset["browser_download_url"]
+                .as_str()
+                .ok_or_else(|| RezToolsError::ConfigError("No download URL found".to_string()))?;
+            let download_url = self.rewrite_for_mirror(download_url);
+            let manifest = self.download_client.download_bytes(&download_url).await?;
+            let manifest = String::from_utf8_lossy(&manifest);
+
+            if asset_name.eq_ignore_ascii_case(&sha256_asset_name) {
+                let digest = manifest
+                    .split_whitespace()
+                    .next()
+                    .ok_or_else(|| RezToolsError::ConfigError(format!(
+                        "Checksum manifest for {} is empty",
+                        filename
+                    )))?;
+                return Ok(digest.to_lowercase());
+            }
+
+            // Combined SHA256SUMS format: "<digest>  <filename>" per line,
+            // filename optionally prefixed with '*' for binary mode
+            for line in manifest.lines() {
+                let mut parts = line.split_whitespace();
+                let Some(digest) = parts.next() else { continue };
+                let Some(entry_name) = parts.next() else { continue };
+                if entry_name.trim_start_matches('*') == filename {
+                    return Ok(digest.to_lowercase());
+                }
+            }
+        }
+
+        Err(RezToolsError::ConfigError(format!(
+            "No checksum manifest found for {}; refusing to install an unverified download",
+            filename
+        )))
+    }
+
+    /// Discover an already-installed system Python interpreter satisfying
+    /// `request`, without downloading anything. On Windows, the `py`
+    /// launcher's `--list-paths` output is parsed for candidates; on Unix,
+    /// `PATH` is scanned for `python3.X`/`python3`/`python` binaries. Each
+    /// candidate is probed with `query_interpreter` and checked against
+    /// `request`; the first match wins. Mirrors how uv falls back to
+    /// `py --list-paths` on Windows before fetching a managed interpreter.
+    fn find_system_python(&self, request: &PythonRequest) -> Option<PathBuf> {
+        let platform = Platform::detect();
+        let candidates = if platform.os == "windows" {
+            Self::list_py_launcher_candidates()
+        } else {
+            Self::list_path_candidates()
+        };
+
+        candidates.into_iter().find(|candidate| {
+            Self::query_interpreter(candidate)
+                .map(|info| request.matches_interpreter(&info))
+                .unwrap_or(false)
+        })
+    }
+
+    /// Parse `py --list-paths` output (`-V:Tag\tC:\path\to\python.exe` lines)
+    /// into candidate interpreter paths. Returns an empty list if the `py`
+    /// launcher isn't installed.
+    fn list_py_launcher_candidates() -> Vec<PathBuf> {
+        let Ok(output) = Command::new("py").arg("--list-paths").output() else {
+            return Vec::new();
+        };
+        if !output.status.success() {
+            return Vec::new();
+        }
+
+        Self::parse_py_launcher_paths(&String::from_utf8_lossy(&output.stdout))
+    }
+
+    /// `list_py_launcher_candidates`'s output-parsing half, split out so it's
+    /// testable without actually invoking the `py` launcher.
+    fn parse_py_launcher_paths(output: &str) -> Vec<PathBuf> {
+        output
+            .lines()
+            .filter_map(|line| {
+                let (_, path) = line.split_once('\t')?;
+                Some(PathBuf::from(path.trim()))
+            })
+            .collect()
+    }
+
+    /// Scan `PATH` for `python3.X`/`python3`/`python` binaries, in `PATH`
+    /// order, newest minor version first within each directory.
+    fn list_path_candidates() -> Vec<PathBuf> {
+        match std::env::var_os("PATH") {
+            Some(path_var) => Self::list_path_candidates_in(&path_var),
+            None => Vec::new(),
+        }
+    }
+
+    /// `list_path_candidates`, parameterized over the `PATH`-like value so
+    /// it can be tested without touching the process environment.
+    fn list_path_candidates_in(path_var: &std::ffi::OsStr) -> Vec<PathBuf> {
+        const NAMES: [&str; 7] = [
+            "python3.13",
+            "python3.12",
+            "python3.11",
+            "python3.10",
+            "python3.9",
+            "python3",
+            "python",
+        ];
+
+        let mut candidates = Vec::new();
+        for dir in std::env::split_paths(path_var) {
+            for name in NAMES {
+                let candidate = dir.join(name);
+                if candidate.is_file() {
+                    candidates.push(candidate);
+                }
+            }
+        }
+        candidates
     }
 
     /// Get the target pattern for the current platform
     fn get_target_pattern(&self, platform: &Platform) -> Result<String> {
         let pattern = match (platform.os.as_str(), platform.arch.as_str()) {
-            ("windows", "x86_64") => "x86_64-pc-windows-msvc-install_only",
-            ("linux", "x86_64") => "x86_64-unknown-linux-gnu-install_only",
-            ("macos", "x86_64") => "x86_64-apple-darwin-install_only",
-            ("macos", "aarch64") => "aarch64-apple-darwin-install_only",
+            ("windows", "x86_64") => "x86_64-pc-windows-msvc-install_only".to_string(),
+            ("linux", "x86_64") => format!(
+                "x86_64-unknown-linux-{}-install_only",
+                Self::detect_linux_libc()
+            ),
+            ("linux", "aarch64") => format!(
+                "aarch64-unknown-linux-{}-install_only",
+                Self::detect_linux_libc()
+            ),
+            ("linux", "arm") => "armv7-unknown-linux-gnueabihf-install_only".to_string(),
+            ("macos", "x86_64") => "x86_64-apple-darwin-install_only".to_string(),
+            ("macos", "aarch64") => "aarch64-apple-darwin-install_only".to_string(),
             _ => return Err(RezToolsError::ConfigError(format!(
                 "Unsupported platform: {}-{}",
                 platform.os, platform.arch
             ))),
         };
 
-        Ok(pattern.to_string())
+        Ok(pattern)
     }
 
-    /// Find the best matching asset from the release
-    fn find_best_asset(&self, assets: &[Value], target_pattern: &str) -> Result<(String, String)> {
-        // Preferred Python versions in order
-        let preferred_versions = ["3.11", "3.12", "3.10", "3.9"];
+    /// Detect whether this Linux host uses musl libc (e.g. Alpine) rather
+    /// than glibc, so musl builds are selected on musl systems instead of a
+    /// glibc build that won't run there. Checks for musl's dynamic loader
+    /// under `/lib`/`/lib64` first, falling back to `ldd --version`'s
+    /// output; this mirrors the detection uv's manylinux/musllinux probing
+    /// does. Defaults to `"gnu"` when neither signal is conclusive.
+    fn detect_linux_libc() -> &'static str {
+        let musl_loader_present = ["/lib", "/lib64"].iter().any(|dir| {
+            std::fs::read_dir(dir)
+                .map(|entries| {
+                    entries.flatten().any(|entry| {
+                        entry
+                            .file_name()
+                            .to_str()
+                            .map(|name| name.starts_with("ld-musl-"))
+                            .unwrap_or(false)
+                    })
+                })
+                .unwrap_or(false)
+        });
+        if musl_loader_present {
+            return "musl";
+        }
 
-        for version in &preferred_versions {
-            for asset in assets {
-                let asset_name = asset["name"].as_str().unwrap_or("");
+        if let Ok(output) = Command::new("ldd").arg("--version").output() {
+            let combined = format!(
+                "{}{}",
+                String::from_utf8_lossy(&output.stdout),
+                String::from_utf8_lossy(&output.stderr)
+            );
+            if combined.to_lowercase().contains("musl") {
+                return "musl";
+            }
+        }
 
-                // Check if this asset matches our criteria
-                if asset_name.contains(target_pattern)
-                    && asset_name.contains(version)
-                    && asset_name.ends_with(".tar.gz") {
+        "gnu"
+    }
 
-                    let download_url = asset["browser_download_url"]
-                        .as_str()
-                        .ok_or_else(|| RezToolsError::ConfigError("No download URL found".to_string()))?;
+    /// Find the best matching asset from the release: every asset whose name
+    /// matches `target_pattern` and parses into an `AssetInfo` satisfying
+    /// `request` is a candidate, and the newest one wins, with `.tar.zst`
+    /// preferred over `.tar.gz` at equal versions since it extracts faster.
+    /// This replaces walking a fixed version priority list, so an explicit
+    /// range like `">=3.11,<3.13"` picks the newest release in range rather
+    /// than whichever bound happens to come first.
+    fn find_best_asset(
+        &self,
+        assets: &[Value],
+        target_pattern: &str,
+        request: &PythonRequest,
+    ) -> Result<(String, String)> {
+        let mut candidates: Vec<(AssetInfo, &str, &str)> = Vec::new();
+
+        for asset in assets {
+            let asset_name = asset["name"].as_str().unwrap_or("");
+            if !asset_name.contains(target_pattern) {
+                continue;
+            }
+            let Some(info) = parse_asset_name(asset_name) else {
+                continue;
+            };
+            if !request.matches(&info) {
+                continue;
+            }
+            let Some(download_url) = asset["browser_download_url"].as_str() else {
+                continue;
+            };
+            candidates.push((info, asset_name, download_url));
+        }
 
-                    return Ok((download_url.to_string(), asset_name.to_string()));
-                }
+        candidates.sort_by_key(|(info, ..)| {
+            (info.major, info.minor, info.patch, info.is_tar_zst)
+        });
+
+        candidates
+            .into_iter()
+            .next_back()
+            .map(|(_, name, url)| (url.to_string(), name.to_string()))
+            .ok_or_else(|| RezToolsError::ConfigError(format!(
+                "No suitable Python Build Standalone found for pattern: {}{}",
+                target_pattern,
+                request.describe(),
+            )))
+    }
+
+    /// Look in `bootstrap_dir` for a vendored archive matching
+    /// `target_pattern` and `request`, the same way `find_best_asset` picks
+    /// the newest matching release asset, but over local files instead of a
+    /// GitHub release listing. Returns `None` (not an error) when nothing
+    /// matches, so the caller falls back to the network.
+    fn find_local_bootstrap_archive(
+        &self,
+        bootstrap_dir: &Path,
+        target_pattern: &str,
+        request: &PythonRequest,
+    ) -> Option<PathBuf> {
+        let entries = std::fs::read_dir(bootstrap_dir).ok()?;
+
+        let mut candidates: Vec<(AssetInfo, PathBuf)> = Vec::new();
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+                continue;
+            };
+            if !name.contains(target_pattern) {
+                continue;
+            }
+            let Some(info) = parse_asset_name(name) else {
+                continue;
+            };
+            if !request.matches(&info) {
+                continue;
             }
+            candidates.push((info, path));
         }
 
-        Err(RezToolsError::ConfigError(format!(
-            "No suitable Python Build Standalone found for pattern: {}",
-            target_pattern
-        )))
+        candidates.sort_by_key(|(info, _)| (info.major, info.minor, info.patch, info.is_tar_zst));
+        candidates.into_iter().next_back().map(|(_, path)| path)
     }
 
     /// Find the Python executable in the extracted directory
@@ -272,6 +1139,30 @@ mod tests {
         assert_eq!(python_standalone.install_dir, install_dir);
     }
 
+    #[test]
+    fn test_query_interpreter_nonexistent() {
+        let result = PythonStandalone::query_interpreter(Path::new("/nonexistent/python3"));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_query_interpreter_caches_result() {
+        // Requires a real Python interpreter on PATH; skip gracefully if absent.
+        let Ok(which) = Command::new("which").arg("python3").output() else {
+            return;
+        };
+        if !which.status.success() {
+            return;
+        }
+        let python_path =
+            PathBuf::from(String::from_utf8_lossy(&which.stdout).trim().to_string());
+
+        let first = PythonStandalone::query_interpreter(&python_path).unwrap();
+        let second = PythonStandalone::query_interpreter(&python_path).unwrap();
+        assert_eq!(first, second);
+        assert!(first.version.0 >= 2);
+    }
+
     #[test]
     fn test_get_target_pattern_windows() {
         let install_dir = PathBuf::from("/test");
@@ -332,6 +1223,45 @@ mod tests {
         assert_eq!(pattern, "aarch64-apple-darwin-install_only");
     }
 
+    #[test]
+    fn test_get_target_pattern_linux_aarch64() {
+        let install_dir = PathBuf::from("/test");
+        let python_standalone = PythonStandalone::new(install_dir);
+
+        let platform = Platform {
+            os: "linux".to_string(),
+            arch: "aarch64".to_string(),
+            target_triple: "aarch64-unknown-linux-gnu".to_string(),
+        };
+
+        // Whichever libc this host has, the pattern must end in the expected
+        // flavor and carry the requested architecture.
+        let pattern = python_standalone.get_target_pattern(&platform).unwrap();
+        assert!(pattern.starts_with("aarch64-unknown-linux-"));
+        assert!(pattern.ends_with("-install_only"));
+    }
+
+    #[test]
+    fn test_get_target_pattern_linux_armv7() {
+        let install_dir = PathBuf::from("/test");
+        let python_standalone = PythonStandalone::new(install_dir);
+
+        let platform = Platform {
+            os: "linux".to_string(),
+            arch: "arm".to_string(),
+            target_triple: "armv7-unknown-linux-gnueabihf".to_string(),
+        };
+
+        let pattern = python_standalone.get_target_pattern(&platform).unwrap();
+        assert_eq!(pattern, "armv7-unknown-linux-gnueabihf-install_only");
+    }
+
+    #[test]
+    fn test_detect_linux_libc_is_gnu_or_musl() {
+        let libc = PythonStandalone::detect_linux_libc();
+        assert!(libc == "gnu" || libc == "musl");
+    }
+
     #[test]
     fn test_get_target_pattern_unsupported() {
         let install_dir = PathBuf::from("/test");
@@ -468,13 +1398,13 @@ mod tests {
         ];
 
         let target_pattern = "x86_64-pc-windows-msvc-install_only";
-        let result = python_standalone.find_best_asset(&assets, target_pattern);
+        let result = python_standalone.find_best_asset(&assets, target_pattern, &PythonRequest::Default);
 
         assert!(result.is_ok());
         let (url, filename) = result.unwrap();
-        // Should prefer 3.11 (first in preferred_versions list)
-        assert!(filename.contains("3.11"));
-        assert_eq!(url, "https://example.com/python311.tar.gz");
+        // Default now means newest stable CPython, i.e. 3.12
+        assert!(filename.contains("3.12"));
+        assert_eq!(url, "https://example.com/python312.tar.gz");
     }
 
     #[test]
@@ -491,9 +1421,291 @@ mod tests {
         ];
 
         let target_pattern = "x86_64-pc-windows-msvc-install_only";
-        let result = python_standalone.find_best_asset(&assets, target_pattern);
+        let result = python_standalone.find_best_asset(&assets, target_pattern, &PythonRequest::Default);
 
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("No suitable Python Build Standalone found"));
     }
+
+    #[test]
+    fn test_find_best_asset_honors_requested_version() {
+        let install_dir = PathBuf::from("/test");
+        let python_standalone = PythonStandalone::new(install_dir);
+
+        let assets = vec![
+            serde_json::json!({
+                "name": "cpython-3.10.12+20250517-x86_64-pc-windows-msvc-install_only.tar.gz",
+                "browser_download_url": "https://example.com/python310.tar.gz"
+            }),
+            serde_json::json!({
+                "name": "cpython-3.11.12+20250517-x86_64-pc-windows-msvc-install_only.tar.gz",
+                "browser_download_url": "https://example.com/python311.tar.gz"
+            }),
+        ];
+
+        let target_pattern = "x86_64-pc-windows-msvc-install_only";
+        let request = PythonRequest::parse("3.10").unwrap();
+        let result = python_standalone.find_best_asset(&assets, target_pattern, &request);
+
+        assert!(result.is_ok());
+        let (_, filename) = result.unwrap();
+        assert!(filename.contains("3.10"));
+    }
+
+    #[test]
+    fn test_find_best_asset_prefers_tar_zst() {
+        let install_dir = PathBuf::from("/test");
+        let python_standalone = PythonStandalone::new(install_dir);
+
+        let assets = vec![
+            serde_json::json!({
+                "name": "cpython-3.11.12+20250517-x86_64-pc-windows-msvc-install_only.tar.gz",
+                "browser_download_url": "https://example.com/python311.tar.gz"
+            }),
+            serde_json::json!({
+                "name": "cpython-3.11.12+20250517-x86_64-pc-windows-msvc-install_only.tar.zst",
+                "browser_download_url": "https://example.com/python311.tar.zst"
+            }),
+        ];
+
+        let target_pattern = "x86_64-pc-windows-msvc-install_only";
+        let result = python_standalone.find_best_asset(&assets, target_pattern, &PythonRequest::Default);
+
+        assert!(result.is_ok());
+        let (_, filename) = result.unwrap();
+        assert!(filename.ends_with(".tar.zst"));
+    }
+
+    #[test]
+    fn test_find_best_asset_honors_version_range() {
+        let install_dir = PathBuf::from("/test");
+        let python_standalone = PythonStandalone::new(install_dir);
+
+        let assets = vec![
+            serde_json::json!({
+                "name": "cpython-3.10.12+20250517-x86_64-pc-windows-msvc-install_only.tar.gz",
+                "browser_download_url": "https://example.com/python310.tar.gz"
+            }),
+            serde_json::json!({
+                "name": "cpython-3.12.1+20250517-x86_64-pc-windows-msvc-install_only.tar.gz",
+                "browser_download_url": "https://example.com/python312.tar.gz"
+            }),
+            serde_json::json!({
+                "name": "cpython-3.13.0+20250517-x86_64-pc-windows-msvc-install_only.tar.gz",
+                "browser_download_url": "https://example.com/python313.tar.gz"
+            }),
+        ];
+
+        let target_pattern = "x86_64-pc-windows-msvc-install_only";
+        let request = PythonRequest::parse(">=3.11,<3.13").unwrap();
+        let result = python_standalone.find_best_asset(&assets, target_pattern, &request);
+
+        assert!(result.is_ok());
+        let (_, filename) = result.unwrap();
+        assert!(filename.contains("3.12"));
+    }
+
+    #[test]
+    fn test_find_best_asset_any_permits_pypy() {
+        let install_dir = PathBuf::from("/test");
+        let python_standalone = PythonStandalone::new(install_dir);
+
+        let assets = vec![
+            serde_json::json!({
+                "name": "pypy-3.10.15+20250517-x86_64-pc-windows-msvc-install_only.tar.gz",
+                "browser_download_url": "https://example.com/pypy310.tar.gz"
+            }),
+        ];
+
+        let target_pattern = "x86_64-pc-windows-msvc-install_only";
+
+        // Default only permits CPython, so PyPy is rejected...
+        let default_result =
+            python_standalone.find_best_asset(&assets, target_pattern, &PythonRequest::Default);
+        assert!(default_result.is_err());
+
+        // ...but an explicit "pypy3.10" request selects it.
+        let request = PythonRequest::parse("pypy3.10").unwrap();
+        let result = python_standalone.find_best_asset(&assets, target_pattern, &request);
+        assert!(result.is_ok());
+        let (_, filename) = result.unwrap();
+        assert!(filename.starts_with("pypy-3.10"));
+    }
+
+    #[test]
+    fn test_python_request_parse_default_and_any() {
+        assert_eq!(PythonRequest::parse("").unwrap(), PythonRequest::Default);
+        assert_eq!(PythonRequest::parse("any").unwrap(), PythonRequest::Any);
+        assert_eq!(PythonRequest::parse("ANY").unwrap(), PythonRequest::Any);
+    }
+
+    #[test]
+    fn test_python_request_parse_rejects_malformed_version() {
+        // A bare major version like "3" is a legitimate (if broad) constraint.
+        assert!(PythonRequest::parse("3").is_ok());
+        assert!(PythonRequest::parse("; rm -rf /").is_err());
+        assert!(PythonRequest::parse(">=3.11,<abc").is_err());
+    }
+
+    fn sample_interpreter_info(implementation_name: &str, version: (u32, u32, u32)) -> InterpreterInfo {
+        InterpreterInfo {
+            version,
+            implementation_name: implementation_name.to_string(),
+            machine: "x86_64".to_string(),
+            prefix: PathBuf::from("/opt/python"),
+            base_prefix: PathBuf::from("/opt/python"),
+            pointer_width: 64,
+            abi_tag: Some("cpython-311-x86_64-linux-gnu".to_string()),
+            platform_tag: "linux-x86_64".to_string(),
+            stdlib_path: PathBuf::from("/opt/python/lib"),
+            purelib_path: PathBuf::from("/opt/python/lib/site-packages"),
+            scripts_path: PathBuf::from("/opt/python/bin"),
+            executable: PathBuf::from("/opt/python/bin/python3"),
+            rez_importable: false,
+            rez_version: None,
+        }
+    }
+
+    #[test]
+    fn test_python_request_matches_interpreter_default_rejects_pypy() {
+        let cpython = sample_interpreter_info("cpython", (3, 11, 9));
+        let pypy = sample_interpreter_info("pypy", (3, 10, 14));
+
+        assert!(PythonRequest::Default.matches_interpreter(&cpython));
+        assert!(!PythonRequest::Default.matches_interpreter(&pypy));
+    }
+
+    #[test]
+    fn test_python_request_matches_interpreter_honors_version_constraint() {
+        let request = PythonRequest::parse("3.11").unwrap();
+        let matching = sample_interpreter_info("cpython", (3, 11, 9));
+        let mismatched = sample_interpreter_info("cpython", (3, 10, 9));
+
+        assert!(request.matches_interpreter(&matching));
+        assert!(!request.matches_interpreter(&mismatched));
+    }
+
+    #[test]
+    fn test_list_path_candidates_in_finds_python_on_path() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("python3"), "fake python").unwrap();
+
+        let path_var = std::env::join_paths([temp_dir.path()]).unwrap();
+        let candidates = PythonStandalone::list_path_candidates_in(&path_var);
+
+        assert!(candidates.contains(&temp_dir.path().join("python3")));
+    }
+
+    #[test]
+    fn test_list_path_candidates_in_skips_missing_directories() {
+        let path_var = std::env::join_paths([PathBuf::from("/definitely/does/not/exist")]).unwrap();
+        let candidates = PythonStandalone::list_path_candidates_in(&path_var);
+        assert!(candidates.is_empty());
+    }
+
+    #[test]
+    fn test_parse_py_launcher_paths() {
+        let output = "-V:3.12 *\tC:\\Python312\\python.exe\n-V:3.11\tC:\\Python311\\python.exe\n";
+        let candidates = PythonStandalone::parse_py_launcher_paths(output);
+        assert_eq!(
+            candidates,
+            vec![
+                PathBuf::from("C:\\Python312\\python.exe"),
+                PathBuf::from("C:\\Python311\\python.exe"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rewrite_for_mirror_no_mirror_configured() {
+        let install_dir = PathBuf::from("/test");
+        let python_standalone = PythonStandalone::new(install_dir);
+        let url = "https://github.com/astral-sh/python-build-standalone/releases/download/20240415/cpython-3.12.1-x86_64-unknown-linux-gnu-install_only.tar.gz";
+
+        assert_eq!(python_standalone.rewrite_for_mirror(url), url);
+    }
+
+    #[test]
+    fn test_rewrite_for_mirror_keeps_tag_and_filename() {
+        let mut python_standalone = PythonStandalone::new(PathBuf::from("/test"));
+        python_standalone.mirror_base_url = Some("https://mirror.example.com/pbs".to_string());
+        let url = "https://github.com/astral-sh/python-build-standalone/releases/download/20240415/cpython-3.12.1-x86_64-unknown-linux-gnu-install_only.tar.gz";
+
+        assert_eq!(
+            python_standalone.rewrite_for_mirror(url),
+            "https://mirror.example.com/pbs/20240415/cpython-3.12.1-x86_64-unknown-linux-gnu-install_only.tar.gz"
+        );
+    }
+
+    #[test]
+    fn test_find_local_bootstrap_archive_picks_newest_match() {
+        let temp_dir = TempDir::new().unwrap();
+        let bootstrap_dir = temp_dir.path();
+        for name in [
+            "cpython-3.10.12+20250517-x86_64-unknown-linux-gnu-install_only.tar.gz",
+            "cpython-3.12.1+20250517-x86_64-unknown-linux-gnu-install_only.tar.gz",
+            "cpython-3.12.1+20250517-x86_64-apple-darwin-install_only.tar.gz",
+        ] {
+            fs::write(bootstrap_dir.join(name), "fake archive").unwrap();
+        }
+
+        let python_standalone = PythonStandalone::new(PathBuf::from("/test"));
+        let found = python_standalone
+            .find_local_bootstrap_archive(
+                bootstrap_dir,
+                "x86_64-unknown-linux-gnu-install_only",
+                &PythonRequest::Default,
+            )
+            .unwrap();
+
+        assert_eq!(
+            found.file_name().unwrap().to_str().unwrap(),
+            "cpython-3.12.1+20250517-x86_64-unknown-linux-gnu-install_only.tar.gz"
+        );
+    }
+
+    #[test]
+    fn test_find_local_bootstrap_archive_no_match_returns_none() {
+        let temp_dir = TempDir::new().unwrap();
+        let python_standalone = PythonStandalone::new(PathBuf::from("/test"));
+
+        let found = python_standalone.find_local_bootstrap_archive(
+            temp_dir.path(),
+            "x86_64-unknown-linux-gnu-install_only",
+            &PythonRequest::Default,
+        );
+
+        assert!(found.is_none());
+    }
+
+    #[test]
+    fn test_resolve_request_from_dir_reads_python_version_file() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join(".python-version"), "3.11\n").unwrap();
+
+        let nested = temp_dir.path().join("a").join("b");
+        fs::create_dir_all(&nested).unwrap();
+
+        let request = PythonStandalone::resolve_request_from_dir(&nested).unwrap();
+        assert_eq!(request, PythonRequest::parse("3.11").unwrap());
+    }
+
+    #[test]
+    fn test_resolve_request_from_dir_skips_comments_and_blank_lines() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join(".python-version"),
+            "# pinned for CI\n\n3.12.1\n",
+        )
+        .unwrap();
+
+        let request = PythonStandalone::resolve_request_from_dir(temp_dir.path()).unwrap();
+        assert_eq!(request, PythonRequest::parse("3.12.1").unwrap());
+    }
+
+    #[test]
+    fn test_resolve_request_from_dir_no_pin_file_returns_none() {
+        let temp_dir = TempDir::new().unwrap();
+        assert!(PythonStandalone::resolve_request_from_dir(temp_dir.path()).is_none());
+    }
 }
@@ -2,7 +2,7 @@ use crate::error::{Result, RezToolsError};
 use crate::platform::detection::detect_rez_environment;
 use crate::platform::Platform;
 use log::{debug, info, warn};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::OnceLock;
 
 /// Global rez path cache
@@ -11,30 +11,84 @@ static REZ_PATH_CACHE: OnceLock<Option<PathBuf>> = OnceLock::new();
 /// Environment variable name for rez path
 const REZ_PATH_ENV: &str = "REZ_PATH";
 
+/// Marker file rez's own installer writes into the `bin` directory of a
+/// production install, containing the installed rez version string. Modeled
+/// on rez's `rez_bin_path` production-install check.
+const REZ_PRODUCTION_INSTALL_MARKER: &str = ".rez_production_install";
+
+/// A resolved rez executable, plus what's known about its provenance: a
+/// `.rez_production_install` marker in its bin directory confirms it's a
+/// real packaged rez rather than e.g. a stray dev checkout's `rez` script
+/// that happened to be first on `PATH`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RezInstall {
+    pub path: PathBuf,
+    /// Version string read from `.rez_production_install`, if `production`.
+    pub version: Option<String>,
+    /// Whether `.rez_production_install` was found alongside `path`.
+    pub production: bool,
+}
+
+/// Read `.rez_production_install` from `exe_path`'s containing directory, if
+/// present, returning the version string recorded in it.
+fn read_production_install_marker(exe_path: &Path) -> Option<String> {
+    let marker = exe_path.parent()?.join(REZ_PRODUCTION_INSTALL_MARKER);
+    let contents = std::fs::read_to_string(marker).ok()?;
+    Some(contents.trim().to_string())
+}
+
+/// Classify a candidate rez path found by one of `find_rez_executable`'s
+/// strategies into a [`RezInstall`].
+fn classify_rez_install(path: PathBuf) -> RezInstall {
+    match read_production_install_marker(&path) {
+        Some(version) => RezInstall {
+            path,
+            version: Some(version),
+            production: true,
+        },
+        None => RezInstall {
+            path,
+            version: None,
+            production: false,
+        },
+    }
+}
+
 /// Find and cache the rez executable path
-pub fn find_and_set_rez_path() -> Result<PathBuf> {
+pub fn find_and_set_rez_path() -> Result<RezInstall> {
     // Check if we already have a cached path
     if let Some(Some(path)) = REZ_PATH_CACHE.get() {
         debug!("Using cached rez path: {}", path.display());
-        return Ok(path.clone());
+        return Ok(classify_rez_install(path.clone()));
     }
 
     // Try to find rez path
-    let rez_path = find_rez_executable()?;
+    let install = find_rez_executable()?;
 
     // Set environment variable
-    std::env::set_var(REZ_PATH_ENV, &rez_path);
+    std::env::set_var(REZ_PATH_ENV, &install.path);
     info!(
         "Set REZ_PATH environment variable to: {}",
-        rez_path.display()
+        install.path.display()
     );
+    if install.production {
+        info!(
+            "Verified production rez install (version {})",
+            install.version.as_deref().unwrap_or("unknown")
+        );
+    } else {
+        warn!(
+            "Rez install at {} has no .rez_production_install marker; this may be a dev checkout rather than a packaged install",
+            install.path.display()
+        );
+    }
 
     // Cache the result
     REZ_PATH_CACHE
-        .set(Some(rez_path.clone()))
+        .set(Some(install.path.clone()))
         .map_err(|_| RezToolsError::ConfigError("Failed to cache rez path".to_string()))?;
 
-    Ok(rez_path)
+    Ok(install)
 }
 
 /// Get the cached rez path, or find it if not cached
@@ -43,7 +97,7 @@ pub fn get_rez_path() -> Result<PathBuf> {
         return Ok(path.clone());
     }
 
-    find_and_set_rez_path()
+    find_and_set_rez_path().map(|install| install.path)
 }
 
 /// Clear the cached rez path (useful for testing)
@@ -52,54 +106,72 @@ pub fn clear_rez_path_cache() {
     let _ = REZ_PATH_CACHE.set(None);
 }
 
-/// Find rez executable using multiple strategies
-fn find_rez_executable() -> Result<PathBuf> {
-    debug!("Searching for rez executable...");
-
-    // Strategy 1: Check REZ_PATH environment variable
-    if let Ok(env_path) = std::env::var(REZ_PATH_ENV) {
-        let path = PathBuf::from(env_path);
-        if path.exists() {
-            debug!(
-                "Found rez via REZ_PATH environment variable: {}",
-                path.display()
-            );
-            return Ok(path);
-        } else {
-            warn!(
-                "REZ_PATH environment variable points to non-existent path: {}",
-                path.display()
-            );
-        }
-    }
-
-    // Strategy 2: Check our Python Build Standalone installation
-    if let Ok(path) = find_rez_in_python_standalone() {
-        debug!("Found rez in Python Build Standalone: {}", path.display());
-        return Ok(path);
-    }
+/// Explicitly set the rez path, bypassing auto-detection. Used after
+/// installing rez into a managed prefix (e.g. the standalone installer) so
+/// the freshly installed interpreter is used immediately instead of being
+/// rediscovered on the next lookup. The cache can only be filled once: if an
+/// earlier lookup already cached a path, that path is kept and only the
+/// `REZ_PATH` environment variable changes.
+pub fn set_rez_path(path: PathBuf) {
+    std::env::set_var(REZ_PATH_ENV, &path);
+    let _ = REZ_PATH_CACHE.set(Some(path));
+}
 
-    // Strategy 3: Check rez-tools wrapper
-    if let Ok(path) = find_rez_wrapper() {
-        debug!("Found rez wrapper: {}", path.display());
-        return Ok(path);
+/// Check the `REZ_PATH` environment variable, the first of
+/// `find_rez_executable`'s strategies.
+fn find_rez_via_env() -> Result<PathBuf> {
+    let env_path = std::env::var(REZ_PATH_ENV)
+        .map_err(|_| RezToolsError::ConfigError("REZ_PATH is not set".to_string()))?;
+    let path = PathBuf::from(env_path);
+    if path.exists() {
+        Ok(path)
+    } else {
+        warn!(
+            "REZ_PATH environment variable points to non-existent path: {}",
+            path.display()
+        );
+        Err(RezToolsError::ConfigError(format!(
+            "REZ_PATH points to non-existent path: {}",
+            path.display()
+        )))
     }
+}
 
-    // Strategy 4: Check system PATH
-    if let Ok(path) = find_rez_in_system_path() {
-        debug!("Found rez in system PATH: {}", path.display());
-        return Ok(path);
-    }
+/// Find rez executable using multiple strategies. Candidates are tried in
+/// order, but a strategy that finds a verified `.rez_production_install`
+/// wins over an earlier strategy's unverified match, the same way rez's own
+/// `rez_bin_path` prefers a validated production install.
+fn find_rez_executable() -> Result<RezInstall> {
+    debug!("Searching for rez executable...");
 
-    // Strategy 5: Check common installation locations
-    if let Ok(path) = find_rez_in_common_locations() {
-        debug!("Found rez in common location: {}", path.display());
-        return Ok(path);
+    let strategies: [(&str, fn() -> Result<PathBuf>); 6] = [
+        ("REZ_PATH environment variable", find_rez_via_env),
+        ("Python Build Standalone", find_rez_in_python_standalone),
+        ("rez-tools wrapper", find_rez_wrapper),
+        ("system PATH", find_rez_in_system_path),
+        ("Windows registry PATH entries", find_rez_via_windows_registry),
+        ("common installation locations", find_rez_in_common_locations),
+    ];
+
+    let mut first_found: Option<RezInstall> = None;
+    for (label, strategy) in strategies {
+        let Ok(path) = strategy() else { continue };
+        debug!("Found rez via {}: {}", label, path.display());
+
+        let candidate = classify_rez_install(path);
+        if candidate.production {
+            return Ok(candidate);
+        }
+        if first_found.is_none() {
+            first_found = Some(candidate);
+        }
     }
 
-    Err(RezToolsError::ConfigError(
-        "Rez executable not found. Please install rez or run 'rt install-rez'".to_string(),
-    ))
+    first_found.ok_or_else(|| {
+        RezToolsError::ConfigError(
+            "Rez executable not found. Please install rez or run 'rt install-rez'".to_string(),
+        )
+    })
 }
 
 /// Find rez in our Python Build Standalone installation
@@ -137,8 +209,11 @@ fn find_rez_in_python_standalone() -> Result<PathBuf> {
             }
         }
 
-        // If no rez script found, we can use python -m rez
-        return Ok(python_path);
+        // If no rez script found, we can use python -m rez, provided this
+        // interpreter can actually import it.
+        if interpreter_can_import_rez(&python_path) {
+            return Ok(python_path);
+        }
     }
 
     Err(RezToolsError::ConfigError(
@@ -214,6 +289,102 @@ fn find_rez_in_system_path() -> Result<PathBuf> {
     }
 }
 
+/// Windows-only: query the registry for user and machine `PATH` and search
+/// each directory for `rez.exe`/`rez.bat`. `where rez` only sees PATH
+/// entries visible in the current session; an install that just added
+/// itself to the registry PATH (installer-style) without a shell restart
+/// is otherwise invisible until this strategy runs.
+fn find_rez_via_windows_registry() -> Result<PathBuf> {
+    if Platform::detect().os != "windows" {
+        return Err(RezToolsError::ConfigError(
+            "Windows registry PATH scan is only available on Windows".to_string(),
+        ));
+    }
+
+    let dirs: Vec<PathBuf> = [
+        (
+            "HKLM\\SYSTEM\\CurrentControlSet\\Control\\Session Manager\\Environment",
+            "PATH",
+        ),
+        ("HKCU\\Environment", "PATH"),
+    ]
+    .into_iter()
+    .filter_map(|(key, value_name)| query_registry_value(key, value_name))
+    .flat_map(|raw_path| {
+        std::env::split_paths(&expand_registry_env_vars(&raw_path)).collect::<Vec<_>>()
+    })
+    .collect();
+
+    for dir in dirs {
+        for name in ["rez.exe", "rez.bat"] {
+            let candidate = dir.join(name);
+            if candidate.exists() {
+                return Ok(candidate);
+            }
+        }
+    }
+
+    Err(RezToolsError::ConfigError(
+        "Rez not found via Windows registry PATH entries".to_string(),
+    ))
+}
+
+/// Run `REG QUERY <key> /v <value_name>` and parse the `REG_SZ`/
+/// `REG_EXPAND_SZ` value out of its output. Returns `None` if the key/value
+/// doesn't exist or `REG` isn't available.
+fn query_registry_value(key: &str, value_name: &str) -> Option<String> {
+    let output = std::process::Command::new("REG")
+        .args(["QUERY", key, "/v", value_name])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    stdout.lines().find_map(|line| {
+        let rest = line.trim().strip_prefix(value_name)?.trim_start();
+        ["REG_EXPAND_SZ", "REG_SZ"]
+            .iter()
+            .find_map(|marker| rest.strip_prefix(marker))
+            .map(|value| value.trim().to_string())
+    })
+}
+
+/// Expand `%VAR%` references in a registry `PATH` value the way `cmd.exe`
+/// would, using the current process environment. An unresolvable `%VAR%`
+/// is left untouched rather than dropped.
+fn expand_registry_env_vars(raw: &str) -> String {
+    let mut result = String::with_capacity(raw.len());
+    let mut rest = raw;
+
+    while let Some(start) = rest.find('%') {
+        result.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        match after.find('%') {
+            Some(end) => {
+                let var_name = &after[..end];
+                match std::env::var(var_name) {
+                    Ok(value) => result.push_str(&value),
+                    Err(_) => {
+                        result.push('%');
+                        result.push_str(var_name);
+                        result.push('%');
+                    }
+                }
+                rest = &after[end + 1..];
+            }
+            None => {
+                result.push('%');
+                rest = after;
+                break;
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
 /// Find rez in common installation locations
 fn find_rez_in_common_locations() -> Result<PathBuf> {
     let platform = Platform::detect();
@@ -242,6 +413,54 @@ fn find_rez_in_common_locations() -> Result<PathBuf> {
     ))
 }
 
+/// Confirm that `python_exe` can actually `import rez`, so the `-m rez`
+/// fallback doesn't hand back a command that's doomed to fail with an
+/// `ImportError` the caller has no good way to diagnose.
+fn interpreter_can_import_rez(python_exe: &Path) -> bool {
+    std::process::Command::new(python_exe)
+        .args(["-c", "import rez"])
+        .output()
+        .map(|output| output.status.success())
+        .unwrap_or(false)
+}
+
+/// Resolve a working Python interpreter from `PATH` for the `python -m rez`
+/// fallback, the way rustc's `x` bootstrap resolves `python3`/`python2`/
+/// `python`: each directory on `PATH` is checked for a plain `python` first
+/// and returned immediately if found, otherwise the first `python3` and
+/// `python2` seen are remembered, and once the whole `PATH` has been
+/// scanned `python3` wins over `python2`.
+fn resolve_python_interpreter() -> Option<PathBuf> {
+    let path_var = std::env::var_os("PATH")?;
+    let exe_suffix = if cfg!(windows) { ".exe" } else { "" };
+
+    let mut python3 = None;
+    let mut python2 = None;
+
+    for dir in std::env::split_paths(&path_var) {
+        let plain = dir.join(format!("python{}", exe_suffix));
+        if plain.is_file() {
+            return Some(plain);
+        }
+
+        if python3.is_none() {
+            let candidate = dir.join(format!("python3{}", exe_suffix));
+            if candidate.is_file() {
+                python3 = Some(candidate);
+            }
+        }
+
+        if python2.is_none() {
+            let candidate = dir.join(format!("python2{}", exe_suffix));
+            if candidate.is_file() {
+                python2 = Some(candidate);
+            }
+        }
+    }
+
+    python3.or(python2)
+}
+
 /// Get rez command for execution (handles python -m rez case)
 pub fn get_rez_command() -> Result<Vec<String>> {
     let rez_path = get_rez_path()?;
@@ -253,8 +472,24 @@ pub fn get_rez_command() -> Result<Vec<String>> {
         .map(|name| name.starts_with("python"))
         .unwrap_or(false)
     {
+        // `rez_path` may not be an interpreter that can actually import
+        // rez (e.g. it's just whatever happened to be resolved earlier);
+        // fall back to probing PATH for one that can before giving up.
+        let python_exe = if interpreter_can_import_rez(&rez_path) {
+            rez_path
+        } else {
+            resolve_python_interpreter()
+                .filter(|candidate| interpreter_can_import_rez(candidate))
+                .ok_or_else(|| {
+                    RezToolsError::ConfigError(format!(
+                        "No Python interpreter on PATH can import rez (tried {})",
+                        rez_path.display()
+                    ))
+                })?
+        };
+
         return Ok(vec![
-            rez_path.to_string_lossy().to_string(),
+            python_exe.to_string_lossy().to_string(),
             "-m".to_string(),
             "rez".to_string(),
         ]);
@@ -354,6 +589,66 @@ mod tests {
         assert_eq!(command[0], rez_exe.to_string_lossy());
     }
 
+    #[test]
+    fn test_resolve_python_interpreter_prefers_plain_python() {
+        let temp_dir = TempDir::new().unwrap();
+        let exe_suffix = if cfg!(windows) { ".exe" } else { "" };
+        fs::write(
+            temp_dir.path().join(format!("python3{}", exe_suffix)),
+            "fake",
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join(format!("python{}", exe_suffix)),
+            "fake",
+        )
+        .unwrap();
+
+        std::env::set_var("PATH", temp_dir.path());
+        let resolved = resolve_python_interpreter().unwrap();
+        assert_eq!(
+            resolved,
+            temp_dir.path().join(format!("python{}", exe_suffix))
+        );
+    }
+
+    #[test]
+    fn test_resolve_python_interpreter_prefers_python3_over_python2() {
+        let temp_dir = TempDir::new().unwrap();
+        let exe_suffix = if cfg!(windows) { ".exe" } else { "" };
+        fs::write(
+            temp_dir.path().join(format!("python2{}", exe_suffix)),
+            "fake",
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join(format!("python3{}", exe_suffix)),
+            "fake",
+        )
+        .unwrap();
+
+        std::env::set_var("PATH", temp_dir.path());
+        let resolved = resolve_python_interpreter().unwrap();
+        assert_eq!(
+            resolved,
+            temp_dir.path().join(format!("python3{}", exe_suffix))
+        );
+    }
+
+    #[test]
+    fn test_resolve_python_interpreter_none_found() {
+        let temp_dir = TempDir::new().unwrap();
+        std::env::set_var("PATH", temp_dir.path());
+        assert!(resolve_python_interpreter().is_none());
+    }
+
+    #[test]
+    fn test_interpreter_can_import_rez_missing_interpreter_is_false() {
+        let temp_dir = TempDir::new().unwrap();
+        let missing = temp_dir.path().join("no-such-python");
+        assert!(!interpreter_can_import_rez(&missing));
+    }
+
     #[test]
     fn test_rez_path_env_variable() {
         let temp_dir = TempDir::new().unwrap();
@@ -368,9 +663,87 @@ mod tests {
 
         let result = find_rez_executable();
         assert!(result.is_ok());
-        assert_eq!(result.unwrap(), fake_rez);
+        let install = result.unwrap();
+        assert_eq!(install.path, fake_rez);
+        assert!(!install.production);
 
         // Clean up
         std::env::remove_var(REZ_PATH_ENV);
     }
+
+    #[test]
+    fn test_find_rez_executable_prefers_production_install() {
+        let temp_dir = TempDir::new().unwrap();
+        let fake_rez = temp_dir.path().join("fake_rez");
+        fs::write(&fake_rez, "fake rez").unwrap();
+        fs::write(
+            temp_dir.path().join(REZ_PRODUCTION_INSTALL_MARKER),
+            "2.114.0\n",
+        )
+        .unwrap();
+
+        std::env::set_var(REZ_PATH_ENV, &fake_rez);
+        clear_rez_path_cache();
+
+        let result = find_rez_executable();
+        assert!(result.is_ok());
+        let install = result.unwrap();
+        assert_eq!(install.path, fake_rez);
+        assert!(install.production);
+        assert_eq!(install.version.as_deref(), Some("2.114.0"));
+
+        std::env::remove_var(REZ_PATH_ENV);
+    }
+
+    #[test]
+    fn test_classify_rez_install_no_marker() {
+        let temp_dir = TempDir::new().unwrap();
+        let rez_exe = temp_dir.path().join("rez");
+        fs::write(&rez_exe, "fake rez").unwrap();
+
+        let install = classify_rez_install(rez_exe.clone());
+        assert_eq!(install.path, rez_exe);
+        assert!(!install.production);
+        assert_eq!(install.version, None);
+    }
+
+    #[test]
+    fn test_expand_registry_env_vars_resolves_known_var() {
+        std::env::set_var("REZ_PATH_TEST_VAR", "C:\\Foo");
+        assert_eq!(
+            expand_registry_env_vars("%REZ_PATH_TEST_VAR%\\bin"),
+            "C:\\Foo\\bin"
+        );
+        std::env::remove_var("REZ_PATH_TEST_VAR");
+    }
+
+    #[test]
+    fn test_expand_registry_env_vars_leaves_unknown_var_untouched() {
+        std::env::remove_var("REZ_PATH_DEFINITELY_UNSET");
+        assert_eq!(
+            expand_registry_env_vars("%REZ_PATH_DEFINITELY_UNSET%\\bin"),
+            "%REZ_PATH_DEFINITELY_UNSET%\\bin"
+        );
+    }
+
+    #[test]
+    fn test_expand_registry_env_vars_no_percent_signs() {
+        assert_eq!(
+            expand_registry_env_vars("C:\\Windows\\System32"),
+            "C:\\Windows\\System32"
+        );
+    }
+
+    #[test]
+    fn test_query_registry_value_parses_reg_sz_line() {
+        let stdout = "\r\nHKCU\\Environment\r\n    PATH    REG_SZ    C:\\a;C:\\b\r\n\r\n";
+        let value = stdout.lines().find_map(|line| {
+            let rest = line.trim().strip_prefix("PATH")?.trim_start();
+            ["REG_EXPAND_SZ", "REG_SZ"]
+                .iter()
+                .find_map(|marker| rest.strip_prefix(marker))
+                .map(|v| v.trim().to_string())
+        });
+        assert_eq!(value.as_deref(), Some("C:\\a;C:\\b"));
+    }
 }
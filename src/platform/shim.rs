@@ -0,0 +1,167 @@
+use crate::error::{Result, RezToolsError};
+use crate::platform::{Platform, RezEnvironment};
+use log::{debug, info};
+use regex::Regex;
+use std::path::{Path, PathBuf};
+use tokio::fs;
+
+/// Pattern a tool name must match before we'll write a shim for it
+const TOOL_NAME_PATTERN: &str = r"^[A-Za-z][\w-]*$";
+
+/// Generate a launcher shim for `tool_name` in `bin_dir` that invokes the
+/// given rez environment's resolved tool. Refuses to overwrite an existing
+/// shim unless `force` is set.
+pub async fn generate_shim(
+    tool_name: &str,
+    env: &RezEnvironment,
+    bin_dir: &Path,
+    force: bool,
+) -> Result<PathBuf> {
+    let pattern = Regex::new(TOOL_NAME_PATTERN)?;
+    if !pattern.is_match(tool_name) {
+        return Err(RezToolsError::ConfigError(format!(
+            "Tool name '{}' does not match required pattern '{}'",
+            tool_name, TOOL_NAME_PATTERN
+        )));
+    }
+
+    fs::create_dir_all(bin_dir).await?;
+
+    let platform = Platform::detect();
+    let shim_path = if platform.os == "windows" {
+        bin_dir.join(format!("{}.cmd", tool_name))
+    } else {
+        bin_dir.join(tool_name)
+    };
+
+    if shim_path.exists() && !force {
+        return Err(RezToolsError::ConfigError(format!(
+            "Shim already exists at {} (use force to overwrite)",
+            shim_path.display()
+        )));
+    }
+
+    let rez_command = build_invocation_command(env)?;
+    let contents = if platform.os == "windows" {
+        render_windows_shim(tool_name, &rez_command)
+    } else {
+        render_unix_shim(tool_name, &rez_command)
+    };
+
+    fs::write(&shim_path, contents).await?;
+
+    if platform.os != "windows" {
+        use std::os::unix::fs::PermissionsExt;
+        let mut permissions = fs::metadata(&shim_path).await?.permissions();
+        permissions.set_mode(0o755);
+        fs::set_permissions(&shim_path, permissions).await?;
+    }
+
+    info!("Generated shim for '{}' at {}", tool_name, shim_path.display());
+    Ok(shim_path)
+}
+
+/// Build the `rez env -q` prefix of the shim's invocation for the detected
+/// environment; the renderers append `<tool> -- <tool>` and the arguments
+fn build_invocation_command(env: &RezEnvironment) -> Result<String> {
+    if let Some(ref rez_path) = env.rez_path {
+        return Ok(format!("\"{}\" env {}", rez_path.display(), "-q"));
+    }
+
+    if let Some(ref python_path) = env.python_path {
+        return Ok(format!("\"{}\" -m rez env -q", python_path.display()));
+    }
+
+    Err(RezToolsError::ConfigError(
+        "Cannot generate shim: no rez or Python interpreter was detected".to_string(),
+    ))
+}
+
+/// Render a Windows `.cmd` wrapper that forwards all arguments
+fn render_windows_shim(tool_name: &str, rez_command: &str) -> String {
+    debug!("Rendering Windows shim for {}", tool_name);
+    format!(
+        "@echo off\r\n{} {} -- {} %*\r\n",
+        rez_command, tool_name, tool_name
+    )
+}
+
+/// Render a POSIX shell wrapper that `exec`s the command, forwarding arguments
+fn render_unix_shim(tool_name: &str, rez_command: &str) -> String {
+    debug!("Rendering Unix shim for {}", tool_name);
+    format!(
+        "#!/bin/sh\nexec {} {} -- {} \"$@\"\n",
+        rez_command, tool_name, tool_name
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use tempfile::TempDir;
+
+    fn test_env() -> RezEnvironment {
+        RezEnvironment {
+            rez_path: Some(PathBuf::from("/usr/local/bin/rez")),
+            python_path: None,
+            packages_path: Vec::new(),
+            is_installed: true,
+            version: Some("Rez 2.0".to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_generate_shim_creates_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let bin_dir = temp_dir.path().join("bin");
+
+        let shim_path = generate_shim("mytool", &test_env(), &bin_dir, false)
+            .await
+            .unwrap();
+
+        assert!(shim_path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_generate_shim_rejects_invalid_name() {
+        let temp_dir = TempDir::new().unwrap();
+        let bin_dir = temp_dir.path().join("bin");
+
+        let result = generate_shim("123bad", &test_env(), &bin_dir, false).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_generate_shim_refuses_overwrite_without_force() {
+        let temp_dir = TempDir::new().unwrap();
+        let bin_dir = temp_dir.path().join("bin");
+
+        generate_shim("mytool", &test_env(), &bin_dir, false)
+            .await
+            .unwrap();
+
+        let result = generate_shim("mytool", &test_env(), &bin_dir, false).await;
+        assert!(result.is_err());
+
+        let result = generate_shim("mytool", &test_env(), &bin_dir, true).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_generate_shim_requires_environment() {
+        let temp_dir = TempDir::new().unwrap();
+        let bin_dir = temp_dir.path().join("bin");
+
+        let empty_env = RezEnvironment {
+            rez_path: None,
+            python_path: None,
+            packages_path: Vec::new(),
+            is_installed: false,
+            version: None,
+        };
+
+        let result = generate_shim("mytool", &empty_env, &bin_dir, false).await;
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,119 @@
+//! Timeout wrapper for external command invocations.
+//!
+//! Every install helper shells out to `uv`/`pip`/`python`, any of which can
+//! hang indefinitely if a network mirror stalls or a subprocess deadlocks.
+//! `run_with_timeout` bounds those calls so a stuck command can't block the
+//! whole CLI, and lets callers like `install_rez`'s fallback chain move on
+//! to the next method instead of hanging forever.
+
+use crate::config::Config;
+use crate::error::{Result, RezToolsError};
+use std::time::Duration;
+use tokio::process::Command as AsyncCommand;
+
+/// Timeouts applied to external command invocations, split by how long the
+/// command is expected to take
+#[derive(Debug, Clone, Copy)]
+pub struct CommandTimeouts {
+    /// Applied to commands that install software (`pip install`, `uv venv`,
+    /// `uv tool install`, ...)
+    pub install: Duration,
+    /// Applied to quick probes (`uv --version`, `pip show`, ...)
+    pub probe: Duration,
+}
+
+impl Default for CommandTimeouts {
+    fn default() -> Self {
+        Self::from_config(&Config::default())
+    }
+}
+
+impl CommandTimeouts {
+    /// Build timeouts from `config`, with the `RT_INSTALL_TIMEOUT_SECS` /
+    /// `RT_PROBE_TIMEOUT_SECS` environment variables taking precedence over
+    /// the config's values
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            install: env_override_secs("RT_INSTALL_TIMEOUT_SECS")
+                .unwrap_or_else(|| Duration::from_secs(config.install_command_timeout_secs)),
+            probe: env_override_secs("RT_PROBE_TIMEOUT_SECS")
+                .unwrap_or_else(|| Duration::from_secs(config.probe_command_timeout_secs)),
+        }
+    }
+}
+
+fn env_override_secs(var: &str) -> Option<Duration> {
+    std::env::var(var)
+        .ok()
+        .and_then(|value| value.parse::<u64>().ok())
+        .map(Duration::from_secs)
+}
+
+/// Run `cmd` to completion, killing it and returning
+/// `RezToolsError::TimeoutError` if it doesn't finish within `timeout`
+pub async fn run_with_timeout(
+    mut cmd: AsyncCommand,
+    timeout: Duration,
+) -> Result<std::process::Output> {
+    // Dropping the `output()` future (as happens when the timeout elapses)
+    // kills the child instead of leaving it running in the background.
+    cmd.kill_on_drop(true);
+
+    match tokio::time::timeout(timeout, cmd.output()).await {
+        Ok(Ok(output)) => Ok(output),
+        Ok(Err(e)) => Err(RezToolsError::ConfigError(format!(
+            "Failed to run command: {}",
+            e
+        ))),
+        Err(_) => Err(RezToolsError::TimeoutError(format!(
+            "Command timed out after {:?}",
+            timeout
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_timeouts() {
+        let timeouts = CommandTimeouts::default();
+        assert_eq!(timeouts.install, Duration::from_secs(300));
+        assert_eq!(timeouts.probe, Duration::from_secs(10));
+    }
+
+    #[test]
+    fn test_env_override_secs_parses_valid_value() {
+        std::env::set_var("RT_TEST_TIMEOUT_OVERRIDE_VALID", "42");
+        assert_eq!(
+            env_override_secs("RT_TEST_TIMEOUT_OVERRIDE_VALID"),
+            Some(Duration::from_secs(42))
+        );
+        std::env::remove_var("RT_TEST_TIMEOUT_OVERRIDE_VALID");
+    }
+
+    #[test]
+    fn test_env_override_secs_ignores_malformed_value() {
+        std::env::set_var("RT_TEST_TIMEOUT_OVERRIDE_INVALID", "not-a-number");
+        assert_eq!(env_override_secs("RT_TEST_TIMEOUT_OVERRIDE_INVALID"), None);
+        std::env::remove_var("RT_TEST_TIMEOUT_OVERRIDE_INVALID");
+    }
+
+    #[tokio::test]
+    async fn test_run_with_timeout_kills_slow_command() {
+        let mut cmd = AsyncCommand::new("sleep");
+        cmd.arg("5");
+
+        let result = run_with_timeout(cmd, Duration::from_millis(50)).await;
+        assert!(matches!(result, Err(RezToolsError::TimeoutError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_run_with_timeout_returns_output_for_fast_command() {
+        let cmd = AsyncCommand::new("true");
+        let result = run_with_timeout(cmd, Duration::from_secs(5)).await;
+        assert!(result.is_ok());
+        assert!(result.unwrap().status.success());
+    }
+}
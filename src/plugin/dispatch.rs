@@ -0,0 +1,103 @@
+use crate::plugin::Plugin;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Looks up the plugin that should handle a given invocation. Modeled on
+/// thin-edge's `plugin_manager` lookup: a plugin set can be searched by
+/// exact name, by the file extension it declares in `handles_extensions`,
+/// or by its `is_default` flag.
+pub trait Plugins {
+    /// Find a plugin registered under `name`
+    fn by_name(&self, name: &str) -> Option<&Plugin>;
+    /// Find a plugin that declares `ext` in its `handles_extensions`
+    fn by_extension(&self, ext: &str) -> Option<&Plugin>;
+    /// Find the plugin marked `is_default`, if any
+    fn default_plugin(&self) -> Option<&Plugin>;
+}
+
+impl Plugins for HashMap<String, Plugin> {
+    fn by_name(&self, name: &str) -> Option<&Plugin> {
+        self.get(name)
+    }
+
+    fn by_extension(&self, ext: &str) -> Option<&Plugin> {
+        self.values().find(|plugin| {
+            plugin
+                .handles_extensions
+                .iter()
+                .any(|handled| handled.eq_ignore_ascii_case(ext))
+        })
+    }
+
+    fn default_plugin(&self) -> Option<&Plugin> {
+        self.values().find(|plugin| plugin.is_default)
+    }
+}
+
+/// Resolve the plugin that should handle `token`, which is an argument that
+/// didn't match any registered subcommand name.
+///
+/// Tries, in order: an exact plugin name match, the plugin that claims
+/// `token`'s file extension, then the configured default plugin.
+pub fn resolve_for_token<'a>(
+    plugins: &'a HashMap<String, Plugin>,
+    token: &str,
+) -> Option<&'a Plugin> {
+    if let Some(plugin) = plugins.by_name(token) {
+        return Some(plugin);
+    }
+
+    if let Some(ext) = Path::new(token).extension().and_then(|e| e.to_str()) {
+        if let Some(plugin) = plugins.by_extension(ext) {
+            return Some(plugin);
+        }
+    }
+
+    plugins.default_plugin()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plugin(name: &str, extensions: &[&str], is_default: bool) -> Plugin {
+        Plugin {
+            command: name.to_string(),
+            name: Some(name.to_string()),
+            short_help: None,
+            requires: vec!["pkg".to_string()],
+            run_detached: false,
+            inherits_from: None,
+            handles_extensions: extensions.iter().map(|e| e.to_string()).collect(),
+            is_default,
+            file_path: Default::default(),
+        }
+    }
+
+    #[test]
+    fn resolves_by_extension() {
+        let mut plugins = HashMap::new();
+        plugins.insert("nuke".to_string(), plugin("nuke", &["nk"], false));
+
+        let resolved = resolve_for_token(&plugins, "shot010.nk").unwrap();
+        assert_eq!(resolved.get_name(), "nuke");
+    }
+
+    #[test]
+    fn falls_back_to_default_plugin() {
+        let mut plugins = HashMap::new();
+        plugins.insert("nuke".to_string(), plugin("nuke", &["nk"], false));
+        plugins.insert("shell".to_string(), plugin("shell", &[], true));
+
+        let resolved = resolve_for_token(&plugins, "unknown-thing").unwrap();
+        assert_eq!(resolved.get_name(), "shell");
+    }
+
+    #[test]
+    fn no_match_returns_none() {
+        let mut plugins = HashMap::new();
+        plugins.insert("nuke".to_string(), plugin("nuke", &["nk"], false));
+
+        assert!(resolve_for_token(&plugins, "shot010.hip").is_none());
+    }
+}
@@ -1,3 +1,4 @@
+pub mod dispatch;
 pub mod parser;
 pub mod scanner;
 
@@ -7,25 +8,77 @@ use std::path::PathBuf;
 /// Represents a rez tool plugin loaded from a .rt file
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Plugin {
-    /// The command to execute
+    /// The command to execute. May be left empty if `inherits_from` names a
+    /// plugin to inherit it from.
+    #[serde(default)]
     pub command: String,
     /// Optional name override (defaults to filename without extension)
     pub name: Option<String>,
     /// Short help description
     pub short_help: Option<String>,
-    /// List of rez packages required
+    /// List of rez packages required. Unioned with the parent's `requires`
+    /// when `inherits_from` is set, so this may be left empty or partial.
+    #[serde(default)]
     pub requires: Vec<String>,
     /// Whether to run the command detached
     #[serde(default)]
     pub run_detached: bool,
     /// Tools this plugin inherits from
     pub inherits_from: Option<String>,
+    /// File extensions (without the leading dot) that this plugin should
+    /// handle when `rt` is invoked with a file argument instead of a
+    /// subcommand name, e.g. `["nk"]` for Nuke scripts
+    #[serde(default)]
+    pub handles_extensions: Vec<String>,
+    /// Whether this plugin is the fallback handler for unrecognized
+    /// commands and files with no matching extension
+    #[serde(default)]
+    pub is_default: bool,
     /// File path where this plugin was loaded from
     #[serde(skip)]
     pub file_path: PathBuf,
 }
 
 impl Plugin {
+    /// Parse a `.rt` plugin document from `content`. `name_hint` becomes
+    /// this plugin's `file_path` (and so its fallback name and the name
+    /// used in parse error messages); a caller reading from a real file
+    /// should overwrite `file_path` with the actual path afterwards, but a
+    /// caller reading from stdin or an in-memory string has no file stem to
+    /// fall back on, so `name_hint` is what `get_name()` uses instead.
+    pub fn from_str(content: &str, name_hint: &str) -> crate::error::Result<Self> {
+        use crate::error::RezToolsError;
+
+        let mut plugin: Plugin = serde_yaml::from_str(content).map_err(|e| {
+            RezToolsError::PluginParseError(format!(
+                "Failed to parse YAML for '{}': {}",
+                name_hint, e
+            ))
+        })?;
+        plugin.file_path = PathBuf::from(name_hint);
+
+        Ok(plugin)
+    }
+
+    /// Expand `~`, environment variable references, and legacy
+    /// `os.path.dirname(__file__)` snippets (see
+    /// [`crate::config::expand::expand`]) in this plugin's `command` and
+    /// `requires` entries in place, using the directory of `file_path` as
+    /// `__file__`'s context. Called once after parsing, before validation.
+    pub fn expand_fields(&mut self) -> crate::error::Result<()> {
+        use crate::config::expand;
+
+        let context_dir = self.file_path.parent();
+        self.command = expand::expand(&self.command, context_dir)?;
+        self.requires = self
+            .requires
+            .iter()
+            .map(|req| expand::expand(req, context_dir))
+            .collect::<crate::error::Result<Vec<_>>>()?;
+
+        Ok(())
+    }
+
     /// Get the effective name of the plugin
     pub fn get_name(&self) -> String {
         if let Some(ref name) = self.name {
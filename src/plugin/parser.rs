@@ -1,6 +1,7 @@
 use crate::error::{Result, RezToolsError};
 use crate::plugin::Plugin;
 use std::fs;
+use std::io::Read;
 use std::path::Path;
 
 /// Parse a .rt file into a Plugin struct
@@ -16,24 +17,51 @@ pub fn parse_plugin_file<P: AsRef<Path>>(file_path: P) -> Result<Plugin> {
         ))
     })?;
 
-    // Parse YAML content
-    let mut plugin: Plugin = serde_yaml::from_str(&content).map_err(|e| {
+    let name_hint = file_path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("unknown");
+    let mut plugin = Plugin::from_str(&content, name_hint)?;
+
+    // Set the real file path (`Plugin::from_str` only has `name_hint` to
+    // work with, so it stands in as a placeholder until now)
+    plugin.file_path = file_path.to_path_buf();
+    plugin.expand_fields()?;
+
+    validate_unless_inheriting(&plugin)?;
+
+    Ok(plugin)
+}
+
+/// Parse a `.rt` plugin document from any `Read`, e.g. stdin, using
+/// `name_hint` as the plugin's name fallback since there's no file stem to
+/// derive one from.
+pub fn parse_plugin_reader<R: Read>(mut reader: R, name_hint: &str) -> Result<Plugin> {
+    let mut content = String::new();
+    reader.read_to_string(&mut content).map_err(|e| {
         RezToolsError::PluginParseError(format!(
-            "Failed to parse YAML in '{}': {}",
-            file_path.display(),
-            e
+            "Failed to read plugin input '{}': {}",
+            name_hint, e
         ))
     })?;
 
-    // Set the file path
-    plugin.file_path = file_path.to_path_buf();
-
-    // Validate the plugin
-    plugin.validate()?;
+    let mut plugin = Plugin::from_str(&content, name_hint)?;
+    plugin.expand_fields()?;
+    validate_unless_inheriting(&plugin)?;
 
     Ok(plugin)
 }
 
+/// A plugin that inherits from another may rely on its parent for
+/// `command`/`requires`, so it's only valid once the scanner has merged it
+/// with its resolved parent chain; validate it there instead.
+fn validate_unless_inheriting(plugin: &Plugin) -> Result<()> {
+    if plugin.inherits_from.is_none() {
+        plugin.validate()?;
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -98,6 +126,58 @@ requires:
         ));
     }
 
+    #[test]
+    fn test_parse_inheriting_plugin_skips_validation() {
+        // `command` and `requires` are missing, but since this plugin
+        // inherits from another, validation is deferred to the scanner's
+        // inheritance-resolution pass rather than failing here.
+        let mut temp_file = NamedTempFile::new().unwrap();
+        writeln!(temp_file, "inherits_from: base_tool").unwrap();
+
+        let plugin = parse_plugin_file(temp_file.path()).unwrap();
+        assert_eq!(plugin.inherits_from, Some("base_tool".to_string()));
+    }
+
+    #[test]
+    fn test_parse_plugin_reader_uses_name_hint() {
+        let content = "command: python\nrequires:\n  - python-3\n";
+
+        let plugin = parse_plugin_reader(content.as_bytes(), "stdin_tool").unwrap();
+        assert_eq!(plugin.command, "python");
+        assert_eq!(plugin.get_name(), "stdin_tool");
+    }
+
+    #[test]
+    fn test_parse_plugin_reader_defers_validation_for_inheriting_plugin() {
+        let content = "inherits_from: base_tool\n";
+
+        let plugin = parse_plugin_reader(content.as_bytes(), "stdin_tool").unwrap();
+        assert_eq!(plugin.inherits_from, Some("base_tool".to_string()));
+    }
+
+    #[test]
+    fn test_parse_plugin_expands_env_var_in_command() {
+        std::env::set_var("REZ_TOOLS_TEST_PARSER_VAR", "maya2024");
+        // A named file: a `NamedTempFile`'s `.tmp...` stem fails the plugin
+        // name pattern, so validation would reject it
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let plugin_file = temp_dir.path().join("maya_tool.rt");
+        let mut file = std::fs::File::create(&plugin_file).unwrap();
+        writeln!(
+            file,
+            r#"
+command: ${{REZ_TOOLS_TEST_PARSER_VAR}}
+requires:
+  - maya-2024
+"#
+        )
+        .unwrap();
+
+        let plugin = parse_plugin_file(&plugin_file).unwrap();
+        assert_eq!(plugin.command, "maya2024");
+        std::env::remove_var("REZ_TOOLS_TEST_PARSER_VAR");
+    }
+
     #[test]
     fn test_parse_missing_required_fields() {
         let mut temp_file = NamedTempFile::new().unwrap();
@@ -1,24 +1,118 @@
+use crate::config::ConfigOrigin;
 use crate::error::{Result, RezToolsError};
 use crate::plugin::{parser::parse_plugin_file, Plugin};
 use glob::glob;
 use log::{debug, warn};
 use std::collections::HashMap;
-use std::path::Path;
+use std::path::PathBuf;
 
-/// Scan for .rt files in the given paths and return a map of plugin name to Plugin
-pub fn scan_plugins<P: AsRef<Path>>(
-    tool_paths: &[P],
+/// Resolve every plugin's `inherits_from` chain in `plugins`, merging each
+/// child into its (transitively resolved) parent, then validate the merged
+/// result. `requires` becomes the union of parent and child (parent first,
+/// de-duplicated); `command`, `short_help`, and `run_detached` are taken
+/// from the parent only where the child left them unset/empty. A chain that
+/// cycles back to itself is reported as a `PluginValidationError` naming the
+/// chain; a parent that doesn't exist is reported naming the plugin that
+/// refers to it and the missing name.
+fn resolve_inheritance(plugins: HashMap<String, Plugin>) -> Result<HashMap<String, Plugin>> {
+    let mut resolved: HashMap<String, Plugin> = HashMap::new();
+
+    let names: Vec<String> = plugins.keys().cloned().collect();
+    for name in &names {
+        if !resolved.contains_key(name) {
+            resolve_one(name, &plugins, &mut resolved, &mut Vec::new())?;
+        }
+    }
+
+    for plugin in resolved.values() {
+        plugin.validate()?;
+    }
+
+    Ok(resolved)
+}
+
+/// Resolve and memoize a single plugin's merge with its parent chain,
+/// tracking `chain` (the names visited on the current recursion path) to
+/// detect cycles.
+fn resolve_one(
+    name: &str,
+    originals: &HashMap<String, Plugin>,
+    resolved: &mut HashMap<String, Plugin>,
+    chain: &mut Vec<String>,
+) -> Result<Plugin> {
+    if let Some(plugin) = resolved.get(name) {
+        return Ok(plugin.clone());
+    }
+
+    if chain.contains(&name.to_string()) {
+        let mut cycle = chain.clone();
+        cycle.push(name.to_string());
+        return Err(RezToolsError::PluginValidationError(format!(
+            "Plugin inheritance cycle detected: {}",
+            cycle.join(" -> ")
+        )));
+    }
+
+    chain.push(name.to_string());
+    let mut plugin = originals
+        .get(name)
+        .cloned()
+        .expect("name came from originals.keys()");
+
+    if let Some(parent_name) = plugin.inherits_from.clone() {
+        if !originals.contains_key(&parent_name) {
+            return Err(RezToolsError::PluginValidationError(format!(
+                "Plugin '{}' inherits from unknown plugin '{}'",
+                name, parent_name
+            )));
+        }
+
+        let parent = resolve_one(&parent_name, originals, resolved, chain)?;
+
+        let mut requires = parent.requires.clone();
+        for req in plugin.requires {
+            if !requires.contains(&req) {
+                requires.push(req);
+            }
+        }
+        plugin.requires = requires;
+
+        if plugin.command.trim().is_empty() {
+            plugin.command = parent.command.clone();
+        }
+        if plugin.short_help.is_none() {
+            plugin.short_help = parent.short_help.clone();
+        }
+        if !plugin.run_detached {
+            plugin.run_detached = parent.run_detached;
+        }
+    }
+
+    chain.pop();
+    resolved.insert(name.to_string(), plugin.clone());
+    Ok(plugin)
+}
+
+/// Scan for .rt files in the given paths and return a map of plugin name to
+/// Plugin. `origins` is parallel to `tool_paths` (as in `Config`'s
+/// `tool_paths`/`tool_path_origins`) and is used only to name the config
+/// file behind a missing directory or a plugin-name collision in warnings.
+pub fn scan_plugins(
+    tool_paths: &[PathBuf],
+    origins: &[ConfigOrigin],
     extension: &str,
 ) -> Result<HashMap<String, Plugin>> {
     let mut plugins = HashMap::new();
-    let mut inheriting_plugins = Vec::new();
+    let mut plugin_origins: HashMap<String, ConfigOrigin> = HashMap::new();
 
     // Process paths in reverse order (like the Python version)
-    for path in tool_paths.iter().rev() {
-        let path = path.as_ref();
-        
+    for (path, origin) in tool_paths.iter().zip(origins.iter()).rev() {
         if !path.exists() {
-            debug!("Tool path does not exist: {}", path.display());
+            warn!(
+                "Tool path from {} does not exist: {}",
+                origin,
+                path.display()
+            );
             continue;
         }
 
@@ -53,24 +147,19 @@ pub fn scan_plugins<P: AsRef<Path>>(
                 }
             };
 
-            // Check if this plugin inherits from another
-            if plugin.inherits_from.is_some() {
-                debug!("Deferring load of sub-plugin {}", plugin.get_name());
-                inheriting_plugins.push(plugin);
-                continue;
-            }
-
             let plugin_name = plugin.get_name();
+            if let Some(previous_origin) = plugin_origins.get(&plugin_name) {
+                warn!(
+                    "Plugin '{}' from {} overrides the one from {}",
+                    plugin_name, origin, previous_origin
+                );
+            }
+            plugin_origins.insert(plugin_name.clone(), origin.clone());
             plugins.insert(plugin_name, plugin);
         }
     }
 
-    // TODO: Handle inheriting plugins (for future implementation)
-    if !inheriting_plugins.is_empty() {
-        warn!("Plugin inheritance is not yet implemented. {} plugins deferred.", inheriting_plugins.len());
-    }
-
-    Ok(plugins)
+    resolve_inheritance(plugins)
 }
 
 #[cfg(test)]
@@ -99,7 +188,12 @@ requires:
         ).unwrap();
 
         // Scan for plugins
-        let plugins = scan_plugins(&[temp_path], ".rt").unwrap();
+        let plugins = scan_plugins(
+            &[temp_path.to_path_buf()],
+            &[ConfigOrigin::Default],
+            ".rt",
+        )
+        .unwrap();
 
         assert_eq!(plugins.len(), 1);
         assert!(plugins.contains_key("test_tool"));
@@ -113,13 +207,110 @@ requires:
     #[test]
     fn test_scan_empty_directory() {
         let temp_dir = TempDir::new().unwrap();
-        let plugins = scan_plugins(&[temp_dir.path()], ".rt").unwrap();
+        let plugins = scan_plugins(
+            &[temp_dir.path().to_path_buf()],
+            &[ConfigOrigin::Default],
+            ".rt",
+        )
+        .unwrap();
         assert!(plugins.is_empty());
     }
 
     #[test]
     fn test_scan_nonexistent_directory() {
-        let plugins = scan_plugins(&[Path::new("/nonexistent/path")], ".rt").unwrap();
+        let plugins = scan_plugins(
+            &[PathBuf::from("/nonexistent/path")],
+            &[ConfigOrigin::Default],
+            ".rt",
+        )
+        .unwrap();
         assert!(plugins.is_empty());
     }
+
+    fn stub_plugin(name: &str, inherits_from: Option<&str>) -> Plugin {
+        Plugin {
+            command: String::new(),
+            name: Some(name.to_string()),
+            short_help: None,
+            requires: Vec::new(),
+            run_detached: false,
+            inherits_from: inherits_from.map(str::to_string),
+            handles_extensions: Vec::new(),
+            is_default: false,
+            file_path: PathBuf::new(),
+        }
+    }
+
+    #[test]
+    fn test_resolve_inheritance_merges_parent_fields() {
+        let mut base = stub_plugin("base_tool", None);
+        base.command = "base-command".to_string();
+        base.short_help = Some("Base help".to_string());
+        base.requires = vec!["base-package".to_string()];
+        base.run_detached = true;
+
+        let mut child = stub_plugin("child_tool", Some("base_tool"));
+        child.requires = vec!["child-package".to_string()];
+
+        let mut plugins = HashMap::new();
+        plugins.insert(base.get_name(), base);
+        plugins.insert(child.get_name(), child);
+
+        let resolved = resolve_inheritance(plugins).unwrap();
+        let child = &resolved["child_tool"];
+
+        assert_eq!(child.command, "base-command");
+        assert_eq!(child.short_help, Some("Base help".to_string()));
+        assert_eq!(child.requires, vec!["base-package", "child-package"]);
+        assert!(child.run_detached);
+    }
+
+    #[test]
+    fn test_resolve_inheritance_resolves_multi_level_chain() {
+        let mut grandparent = stub_plugin("grandparent", None);
+        grandparent.command = "gp-command".to_string();
+        grandparent.requires = vec!["gp-package".to_string()];
+
+        let parent = stub_plugin("parent", Some("grandparent"));
+
+        let mut child = stub_plugin("child", Some("parent"));
+        child.requires = vec!["child-package".to_string()];
+
+        let mut plugins = HashMap::new();
+        plugins.insert(grandparent.get_name(), grandparent);
+        plugins.insert(parent.get_name(), parent);
+        plugins.insert(child.get_name(), child);
+
+        let resolved = resolve_inheritance(plugins).unwrap();
+        let child = &resolved["child"];
+
+        assert_eq!(child.command, "gp-command");
+        assert_eq!(child.requires, vec!["gp-package", "child-package"]);
+    }
+
+    #[test]
+    fn test_resolve_inheritance_detects_cycle() {
+        let a = stub_plugin("a", Some("b"));
+        let b = stub_plugin("b", Some("a"));
+
+        let mut plugins = HashMap::new();
+        plugins.insert(a.get_name(), a);
+        plugins.insert(b.get_name(), b);
+
+        let err = resolve_inheritance(plugins).unwrap_err();
+        assert!(matches!(err, RezToolsError::PluginValidationError(_)));
+        assert!(err.to_string().contains("cycle"));
+    }
+
+    #[test]
+    fn test_resolve_inheritance_rejects_unknown_parent() {
+        let child = stub_plugin("child", Some("missing_parent"));
+
+        let mut plugins = HashMap::new();
+        plugins.insert(child.get_name(), child);
+
+        let err = resolve_inheritance(plugins).unwrap_err();
+        assert!(matches!(err, RezToolsError::PluginValidationError(_)));
+        assert!(err.to_string().contains("missing_parent"));
+    }
 }
@@ -0,0 +1,223 @@
+//! Registry of processes started via `execute_detached`/`execute_rez_command_sync`'s
+//! detached mode.
+//!
+//! Spawning a detached child used to throw away its `Child` handle
+//! entirely, so there was no way to later check whether it was still
+//! running, wait on it, or stop it. This module keeps an in-memory registry
+//! of every detached PID, mirrored best-effort to `~/.rez-tools/detached.json`
+//! so `rt` picks the registry back up across restarts, and exposes
+//! `list_detached`/`wait_detached`/`kill_detached` so a long-running
+//! backgrounded rez tool can be managed later, the same way rez's
+//! `run_rez_shell` hands back a PID the caller can `wait()` on.
+
+use crate::error::{Result, RezToolsError};
+use crate::platform::installer::rez_tools_dir;
+use log::warn;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A process spawned in detached mode, tracked so it can later be queried,
+/// waited on, or killed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DetachedProcess {
+    pub pid: u32,
+    pub command_line: String,
+    /// Unix timestamp (seconds) the process was started at.
+    pub started_at: u64,
+}
+
+static DETACHED_REGISTRY: OnceLock<Mutex<HashMap<u32, DetachedProcess>>> = OnceLock::new();
+
+fn registry() -> &'static Mutex<HashMap<u32, DetachedProcess>> {
+    DETACHED_REGISTRY.get_or_init(|| Mutex::new(load_registry_from_disk()))
+}
+
+/// Path to the on-disk mirror of the detached-process registry.
+fn registry_file_path() -> PathBuf {
+    rez_tools_dir().join("detached.json")
+}
+
+fn load_registry_from_disk() -> HashMap<u32, DetachedProcess> {
+    let Ok(contents) = std::fs::read_to_string(registry_file_path()) else {
+        return HashMap::new();
+    };
+    let Ok(processes) = serde_json::from_str::<Vec<DetachedProcess>>(&contents) else {
+        return HashMap::new();
+    };
+    processes.into_iter().map(|p| (p.pid, p)).collect()
+}
+
+/// Persist the current registry to disk. Best-effort: a failure to persist
+/// only logs a warning, since the in-memory registry remains authoritative
+/// for this `rt` process.
+fn persist_registry(processes: &HashMap<u32, DetachedProcess>) {
+    let path = registry_file_path();
+    let Some(parent) = path.parent() else { return };
+    if let Err(e) = std::fs::create_dir_all(parent) {
+        warn!("Failed to create {}: {}", parent.display(), e);
+        return;
+    }
+
+    let snapshot: Vec<&DetachedProcess> = processes.values().collect();
+    match serde_json::to_string_pretty(&snapshot) {
+        Ok(json) => {
+            if let Err(e) = std::fs::write(&path, json) {
+                warn!("Failed to persist detached process registry: {}", e);
+            }
+        }
+        Err(e) => warn!("Failed to serialize detached process registry: {}", e),
+    }
+}
+
+/// Record a newly spawned detached child.
+pub fn record_detached(pid: u32, command_line: String) {
+    let started_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    let mut processes = registry().lock().unwrap();
+    processes.insert(
+        pid,
+        DetachedProcess {
+            pid,
+            command_line,
+            started_at,
+        },
+    );
+    persist_registry(&processes);
+}
+
+/// Whether `pid` currently refers to a running process.
+fn is_running(pid: u32) -> bool {
+    if cfg!(windows) {
+        std::process::Command::new("tasklist")
+            .args(["/FI", &format!("PID eq {}", pid)])
+            .output()
+            .map(|output| String::from_utf8_lossy(&output.stdout).contains(&pid.to_string()))
+            .unwrap_or(false)
+    } else {
+        std::process::Command::new("ps")
+            .args(["-p", &pid.to_string()])
+            .output()
+            .map(|output| output.status.success())
+            .unwrap_or(false)
+    }
+}
+
+/// Drop any tracked process whose PID is no longer running.
+fn prune_dead(processes: &mut HashMap<u32, DetachedProcess>) -> bool {
+    let before = processes.len();
+    processes.retain(|&pid, _| is_running(pid));
+    processes.len() != before
+}
+
+/// List every process tracked by the detached-process registry, pruning
+/// any whose PID is no longer running.
+pub fn list_detached() -> Vec<DetachedProcess> {
+    let mut processes = registry().lock().unwrap();
+    if prune_dead(&mut processes) {
+        persist_registry(&processes);
+    }
+
+    let mut result: Vec<DetachedProcess> = processes.values().cloned().collect();
+    result.sort_by_key(|p| p.pid);
+    result
+}
+
+/// Block until `pid` is no longer running, polling at a short interval.
+/// Returns an error if `pid` isn't (or is no longer) tracked.
+pub async fn wait_detached(pid: u32) -> Result<()> {
+    if !registry().lock().unwrap().contains_key(&pid) {
+        return Err(RezToolsError::RezExecutionError(format!(
+            "No tracked detached process with PID {}",
+            pid
+        )));
+    }
+
+    while is_running(pid) {
+        tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+    }
+
+    let mut processes = registry().lock().unwrap();
+    processes.remove(&pid);
+    persist_registry(&processes);
+    Ok(())
+}
+
+/// Terminate a tracked detached process and remove it from the registry.
+pub fn kill_detached(pid: u32) -> Result<()> {
+    if !registry().lock().unwrap().contains_key(&pid) {
+        return Err(RezToolsError::RezExecutionError(format!(
+            "No tracked detached process with PID {}",
+            pid
+        )));
+    }
+
+    let status = if cfg!(windows) {
+        std::process::Command::new("taskkill")
+            .args(["/PID", &pid.to_string(), "/F"])
+            .status()
+    } else {
+        std::process::Command::new("kill")
+            .arg(pid.to_string())
+            .status()
+    };
+
+    match status {
+        Ok(status) if status.success() => {
+            let mut processes = registry().lock().unwrap();
+            processes.remove(&pid);
+            persist_registry(&processes);
+            Ok(())
+        }
+        Ok(status) => Err(RezToolsError::RezExecutionError(format!(
+            "Failed to kill PID {}: command exited with {}",
+            pid, status
+        ))),
+        Err(e) => Err(RezToolsError::RezExecutionError(format!(
+            "Failed to kill PID {}: {}",
+            pid, e
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_detached_inserts_into_registry() {
+        // A PID this high is vanishingly unlikely to be a real running
+        // process, so list_detached's liveness prune removes it again;
+        // assert on record_detached's direct registry insert instead.
+        record_detached(999_999, "rez env -- echo hi".to_string());
+        let processes = registry().lock().unwrap();
+        let process = processes.get(&999_999).unwrap();
+        assert_eq!(process.command_line, "rez env -- echo hi");
+        drop(processes);
+        registry().lock().unwrap().remove(&999_999);
+    }
+
+    #[test]
+    fn test_list_detached_prunes_dead_pids() {
+        record_detached(999_998, "rez env -- echo hi".to_string());
+        let processes = list_detached();
+        assert!(!processes.iter().any(|p| p.pid == 999_998));
+    }
+
+    #[tokio::test]
+    async fn test_wait_detached_unknown_pid_errors() {
+        let result = wait_detached(999_997).await;
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_kill_detached_unknown_pid_errors() {
+        let result = kill_detached(999_996);
+        assert!(result.is_err());
+    }
+}
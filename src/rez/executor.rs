@@ -1,9 +1,21 @@
 use crate::error::{Result, RezToolsError};
 use crate::rez::RezCommand;
 use log::{debug, info};
+#[cfg(unix)]
+use std::os::unix::process::CommandExt;
 use std::process::{Command, Stdio};
+use tokio::io::{AsyncBufReadExt, BufReader};
 use tokio::process::Command as AsyncCommand;
 
+/// The result of running a rez command with stdout/stderr captured instead
+/// of inherited or discarded.
+#[derive(Debug, Clone)]
+pub struct CommandOutput {
+    pub exit_code: i32,
+    pub stdout: String,
+    pub stderr: String,
+}
+
 /// Execute a rez command
 pub async fn execute_rez_command(rez_cmd: RezCommand) -> Result<i32> {
     let command_parts = rez_cmd.build_command();
@@ -14,6 +26,8 @@ pub async fn execute_rez_command(rez_cmd: RezCommand) -> Result<i32> {
 
     if rez_cmd.detached {
         execute_detached(&command_parts).await
+    } else if rez_cmd.replace_process {
+        exec_or_status(&command_parts)
     } else {
         execute_attached(&command_parts).await
     }
@@ -54,7 +68,107 @@ async fn execute_attached(command_parts: &[String]) -> Result<i32> {
     Ok(exit_code)
 }
 
-/// Execute command in detached mode (don't wait for completion)
+/// Execute a rez command with its stdout/stderr captured instead of
+/// inherited or discarded, so programmatic callers (e.g. resolving a
+/// context and reading the package list back) can parse what the command
+/// printed rather than only seeing it forwarded to the terminal.
+pub async fn execute_rez_command_capture(rez_cmd: RezCommand) -> Result<CommandOutput> {
+    execute_rez_command_capture_with_callbacks(rez_cmd, None, None).await
+}
+
+/// Like [`execute_rez_command_capture`], but `on_stdout_line`/`on_stderr_line`,
+/// if given, are called with each line as it's produced so a long resolve
+/// can report progress without waiting for the command to finish. Mirrors
+/// rez's `ResolvedContext` resolve callbacks.
+pub async fn execute_rez_command_capture_with_callbacks(
+    rez_cmd: RezCommand,
+    mut on_stdout_line: Option<&mut dyn FnMut(&str)>,
+    mut on_stderr_line: Option<&mut dyn FnMut(&str)>,
+) -> Result<CommandOutput> {
+    let command_parts = rez_cmd.build_command();
+
+    if command_parts.is_empty() {
+        return Err(RezToolsError::RezExecutionError(
+            "Empty command".to_string(),
+        ));
+    }
+
+    let mut cmd = AsyncCommand::new(&command_parts[0]);
+    cmd.args(&command_parts[1..]);
+    cmd.stdin(Stdio::null());
+    cmd.stdout(Stdio::piped());
+    cmd.stderr(Stdio::piped());
+
+    debug!(
+        "Starting captured process: {} {:?}",
+        command_parts[0],
+        &command_parts[1..]
+    );
+
+    let mut child = cmd.spawn().map_err(|e| {
+        RezToolsError::RezExecutionError(format!(
+            "Failed to spawn command '{}': {}",
+            command_parts[0], e
+        ))
+    })?;
+
+    let mut stdout_lines = BufReader::new(child.stdout.take().expect("stdout was piped")).lines();
+    let mut stderr_lines = BufReader::new(child.stderr.take().expect("stderr was piped")).lines();
+
+    let mut stdout = String::new();
+    let mut stderr = String::new();
+    let mut stdout_done = false;
+    let mut stderr_done = false;
+
+    while !stdout_done || !stderr_done {
+        tokio::select! {
+            line = stdout_lines.next_line(), if !stdout_done => {
+                match line.map_err(|e| RezToolsError::RezExecutionError(format!("Failed to read stdout: {}", e)))? {
+                    Some(line) => {
+                        if let Some(callback) = on_stdout_line.as_mut() {
+                            callback(&line);
+                        }
+                        stdout.push_str(&line);
+                        stdout.push('\n');
+                    }
+                    None => stdout_done = true,
+                }
+            }
+            line = stderr_lines.next_line(), if !stderr_done => {
+                match line.map_err(|e| RezToolsError::RezExecutionError(format!("Failed to read stderr: {}", e)))? {
+                    Some(line) => {
+                        if let Some(callback) = on_stderr_line.as_mut() {
+                            callback(&line);
+                        }
+                        stderr.push_str(&line);
+                        stderr.push('\n');
+                    }
+                    None => stderr_done = true,
+                }
+            }
+        }
+    }
+
+    let status = child.wait().await.map_err(|e| {
+        RezToolsError::RezExecutionError(format!(
+            "Failed to wait on command '{}': {}",
+            command_parts[0], e
+        ))
+    })?;
+
+    debug!("Captured process exited with code: {:?}", status.code());
+
+    Ok(CommandOutput {
+        exit_code: status.code().unwrap_or(-1),
+        stdout,
+        stderr,
+    })
+}
+
+/// Execute command in detached mode (don't wait for completion). Returns
+/// the spawned child's PID (0 if the platform couldn't report one) instead
+/// of an exit code, and records it in the [`crate::rez::detached`] registry
+/// so it can later be listed, waited on, or killed.
 async fn execute_detached(command_parts: &[String]) -> Result<i32> {
     if command_parts.is_empty() {
         return Err(RezToolsError::RezExecutionError(
@@ -76,15 +190,69 @@ async fn execute_detached(command_parts: &[String]) -> Result<i32> {
         &command_parts[1..]
     );
 
-    let _child = cmd.spawn().map_err(|e| {
+    let mut child = cmd.spawn().map_err(|e| {
         RezToolsError::RezExecutionError(format!(
             "Failed to spawn detached command '{}': {}",
             command_parts[0], e
         ))
     })?;
 
-    info!("Process started in detached mode");
-    Ok(0)
+    let pid = child.id().unwrap_or(0);
+    if pid != 0 {
+        crate::rez::detached::record_detached(pid, command_parts.join(" "));
+    }
+
+    // Reap the child in the background instead of awaiting it here, so the
+    // detached process keeps running after this function (and `rt`) returns.
+    tokio::spawn(async move {
+        let _ = child.wait().await;
+    });
+
+    info!("Process started in detached mode with PID {}", pid);
+    Ok(pid as i32)
+}
+
+/// Run `command_parts`, replacing the current process image via `exec` on
+/// Unix so there is no extra `rt` layer left in the process tree and
+/// Ctrl-C / job-control signals go straight to the rez command. `exec` only
+/// returns on failure. On Windows, where process replacement isn't
+/// available, this falls back to the same spawn-and-wait-for-status
+/// behavior as the non-replacing attached path, still returning the
+/// child's exit code.
+fn exec_or_status(command_parts: &[String]) -> Result<i32> {
+    if command_parts.is_empty() {
+        return Err(RezToolsError::RezExecutionError(
+            "Empty command".to_string(),
+        ));
+    }
+
+    let mut cmd = Command::new(&command_parts[0]);
+    cmd.args(&command_parts[1..]);
+
+    #[cfg(unix)]
+    {
+        let err = cmd.exec();
+        Err(RezToolsError::RezExecutionError(format!(
+            "Failed to exec command '{}': {}",
+            command_parts[0], err
+        )))
+    }
+
+    #[cfg(not(unix))]
+    {
+        cmd.stdin(Stdio::inherit());
+        cmd.stdout(Stdio::inherit());
+        cmd.stderr(Stdio::inherit());
+
+        let status = cmd.status().map_err(|e| {
+            RezToolsError::RezExecutionError(format!(
+                "Failed to execute command '{}': {}",
+                command_parts[0], e
+            ))
+        })?;
+
+        Ok(status.code().unwrap_or(-1))
+    }
 }
 
 /// Execute a rez command synchronously (for compatibility)
@@ -97,25 +265,31 @@ pub fn execute_rez_command_sync(rez_cmd: RezCommand) -> Result<i32> {
         ));
     }
 
-    let mut cmd = Command::new(&command_parts[0]);
-    cmd.args(&command_parts[1..]);
-
     if rez_cmd.detached {
-        // For detached mode, spawn and don't wait
+        // For detached mode, spawn, record the PID, and don't wait
+        let mut cmd = Command::new(&command_parts[0]);
+        cmd.args(&command_parts[1..]);
         cmd.stdin(Stdio::null());
         cmd.stdout(Stdio::null());
         cmd.stderr(Stdio::null());
 
-        let _child = cmd.spawn().map_err(|e| {
+        let child = cmd.spawn().map_err(|e| {
             RezToolsError::RezExecutionError(format!(
                 "Failed to spawn detached command '{}': {}",
                 command_parts[0], e
             ))
         })?;
 
-        Ok(0)
+        let pid = child.id();
+        crate::rez::detached::record_detached(pid, command_parts.join(" "));
+
+        Ok(pid as i32)
+    } else if rez_cmd.replace_process {
+        exec_or_status(&command_parts)
     } else {
         // For attached mode, inherit stdio and wait
+        let mut cmd = Command::new(&command_parts[0]);
+        cmd.args(&command_parts[1..]);
         cmd.stdin(Stdio::inherit());
         cmd.stdout(Stdio::inherit());
         cmd.stderr(Stdio::inherit());
@@ -145,6 +319,8 @@ mod tests {
             requires: vec!["test-package".to_string()],
             run_detached: false,
             inherits_from: None,
+            handles_extensions: Vec::new(),
+            is_default: false,
             file_path: PathBuf::from("test.rt"),
         }
     }
@@ -192,4 +368,47 @@ mod tests {
         assert_eq!(command[6], "-c");
         assert_eq!(command[7], "print('hello')");
     }
+
+    #[tokio::test]
+    async fn test_execute_rez_command_capture_returns_exit_code_or_spawn_error() {
+        // Whether a real `rez` (or its fallback) is on PATH depends on the
+        // machine running this test, so we don't assert a specific outcome,
+        // only that capture mode never panics and reports failures as a
+        // RezExecutionError rather than hanging.
+        let plugin = create_test_plugin();
+        let rez_cmd = RezCommand::new(plugin);
+
+        match execute_rez_command_capture(rez_cmd).await {
+            Ok(output) => {
+                println!(
+                    "Captured rez command exited with code {}",
+                    output.exit_code
+                );
+            }
+            Err(RezToolsError::RezExecutionError(_)) => {}
+            Err(e) => panic!("Unexpected error variant: {:?}", e),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execute_rez_command_capture_with_callbacks_empty_plugin_command() {
+        let plugin = Plugin {
+            command: String::new(),
+            name: Some("empty".to_string()),
+            short_help: None,
+            requires: Vec::new(),
+            run_detached: false,
+            inherits_from: None,
+            handles_extensions: Vec::new(),
+            is_default: false,
+            file_path: PathBuf::from("empty.rt"),
+        };
+        // `build_command` never actually produces an empty vector (it always
+        // includes at least the rez path and "env"), so this exercises the
+        // ordinary capture path rather than the empty-command guard; it
+        // still confirms the callback-driven variant runs end to end.
+        let rez_cmd = RezCommand::new(plugin).with_ignore_cmd(true);
+        let result = execute_rez_command_capture_with_callbacks(rez_cmd, None, None).await;
+        assert!(result.is_ok() || matches!(result, Err(RezToolsError::RezExecutionError(_))));
+    }
 }
@@ -1,3 +1,4 @@
+pub mod detached;
 pub mod executor;
 
 use crate::plugin::Plugin;
@@ -15,6 +16,10 @@ pub struct RezCommand {
     pub detached: bool,
     /// Whether to ignore the default command and use args as the command
     pub ignore_cmd: bool,
+    /// Whether to replace the current process image via `exec` on Unix
+    /// instead of spawning a child and waiting on it. Ignored when
+    /// `detached` is set. See [`crate::rez::executor::execute_rez_command`].
+    pub replace_process: bool,
 }
 
 impl RezCommand {
@@ -25,6 +30,7 @@ impl RezCommand {
             plugin,
             args: Vec::new(),
             ignore_cmd: false,
+            replace_process: false,
         }
     }
 
@@ -46,6 +52,13 @@ impl RezCommand {
         self
     }
 
+    /// Set whether to replace the current process image via `exec` on Unix
+    /// instead of spawning a child and waiting for its exit code.
+    pub fn with_replace_process(mut self, replace_process: bool) -> Self {
+        self.replace_process = replace_process;
+        self
+    }
+
     /// Build the complete rez command line
     pub fn build_command(&self) -> Vec<String> {
         // Use the unified rez path management
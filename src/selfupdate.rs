@@ -0,0 +1,328 @@
+//! Self-update support: check GitHub releases for a newer `rez-tools` build
+//! and atomically swap it in for the currently running executable.
+
+use crate::error::{Result, RezToolsError};
+use crate::platform::{download::DownloadClient, extract::Extractor, Platform};
+use log::{debug, info};
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+
+/// GitHub repository that publishes rez-tools releases
+const RELEASES_REPO: &str = "loonghao/rez-tools";
+
+/// Result of checking the latest GitHub release against the running version
+#[derive(Debug, Clone)]
+pub struct UpdateCheck {
+    pub current_version: String,
+    pub latest_version: String,
+    pub update_available: bool,
+    asset_name: String,
+    download_url: String,
+}
+
+/// Checks for and installs newer `rez-tools` releases from GitHub
+pub struct SelfUpdater {
+    download_client: DownloadClient,
+    repo: String,
+}
+
+impl Default for SelfUpdater {
+    fn default() -> Self {
+        Self::new(RELEASES_REPO)
+    }
+}
+
+impl SelfUpdater {
+    /// Create an updater that checks `owner/repo` for releases
+    pub fn new(repo: &str) -> Self {
+        Self {
+            download_client: DownloadClient::new(),
+            repo: repo.to_string(),
+        }
+    }
+
+    /// Query the latest GitHub release and compare it against the running version
+    pub async fn check_for_update(&self) -> Result<UpdateCheck> {
+        let current_version = env!("CARGO_PKG_VERSION").to_string();
+        let api_url = format!(
+            "https://api.github.com/repos/{}/releases/latest",
+            self.repo
+        );
+
+        info!("Checking {} for the latest release", self.repo);
+        let release: Value = self
+            .download_client
+            .download_bytes(&api_url)
+            .await
+            .and_then(|bytes| {
+                serde_json::from_slice(&bytes).map_err(|e| {
+                    RezToolsError::UpdateError(format!("Failed to parse release metadata: {}", e))
+                })
+            })?;
+
+        let latest_version = release["tag_name"]
+            .as_str()
+            .map(|s| s.trim_start_matches('v').to_string())
+            .ok_or_else(|| {
+                RezToolsError::UpdateError("Release metadata has no tag_name".to_string())
+            })?;
+
+        let platform = Platform::detect();
+        let asset_name = asset_name_for_platform(&platform);
+
+        let assets = release["assets"].as_array().ok_or_else(|| {
+            RezToolsError::UpdateError("Release metadata has no assets".to_string())
+        })?;
+
+        let asset = assets
+            .iter()
+            .find(|asset| asset["name"].as_str() == Some(asset_name.as_str()))
+            .ok_or_else(|| {
+                RezToolsError::UpdateError(format!(
+                    "No release asset named '{}' for this platform",
+                    asset_name
+                ))
+            })?;
+
+        let download_url = asset["browser_download_url"]
+            .as_str()
+            .ok_or_else(|| RezToolsError::UpdateError("Release asset has no download URL".to_string()))?
+            .to_string();
+
+        let update_available = match (parse_version(&latest_version), parse_version(&current_version)) {
+            (Some(latest), Some(current)) => latest > current,
+            _ => false,
+        };
+
+        Ok(UpdateCheck {
+            current_version,
+            latest_version,
+            update_available,
+            asset_name,
+            download_url,
+        })
+    }
+
+    /// Download the update described by `check`, verify it against the
+    /// release's `<asset>.sha256` when one is published (without one, it is
+    /// installed unverified), and install it in place of the currently
+    /// running executable
+    pub async fn apply_update(&self, check: &UpdateCheck) -> Result<()> {
+        if !check.update_available {
+            return Err(RezToolsError::UpdateError(
+                "No newer version available to install".to_string(),
+            ));
+        }
+
+        let current_exe = std::env::current_exe().map_err(|e| {
+            RezToolsError::UpdateError(format!("Failed to locate running executable: {}", e))
+        })?;
+
+        let work_dir =
+            std::env::temp_dir().join(format!("rez-tools-update-{}", check.latest_version));
+        tokio::fs::create_dir_all(&work_dir).await?;
+
+        let archive_path = work_dir.join(&check.asset_name);
+        let checksum_url = format!("{}.sha256", check.download_url);
+        let expected_checksum = self
+            .download_client
+            .download_bytes(&checksum_url)
+            .await
+            .ok()
+            .and_then(|bytes| String::from_utf8(bytes).ok())
+            .and_then(|text| text.split_whitespace().next().map(|s| s.to_lowercase()));
+
+        match expected_checksum {
+            Some(checksum) if !checksum.is_empty() => {
+                debug!("Verifying {} against {}", check.asset_name, checksum_url);
+                self.download_client
+                    .download_file_verified(&check.download_url, &archive_path, &checksum)
+                    .await?;
+            }
+            _ => {
+                info!(
+                    "No checksum found at {}; downloading {} without verification",
+                    checksum_url, check.asset_name
+                );
+                self.download_client
+                    .download_file(&check.download_url, &archive_path)
+                    .await?;
+            }
+        }
+
+        let extract_dir = work_dir.join("extracted");
+        Extractor::extract(&archive_path, &extract_dir).await?;
+
+        let new_exe_name = format!("rez-tools{}", Platform::detect().exe_extension());
+        let new_exe = find_new_executable(&extract_dir, &new_exe_name)?;
+
+        install_new_executable(&current_exe, &new_exe).await?;
+
+        info!("Updated rez-tools to version {}", check.latest_version);
+        Ok(())
+    }
+}
+
+/// The release asset name this platform expects, e.g. `rez-tools-x86_64-linux.tar.gz`
+fn asset_name_for_platform(platform: &Platform) -> String {
+    let ext = if platform.os == "windows" { "zip" } else { "tar.gz" };
+    format!("rez-tools-{}.{}", platform.target_triple, ext)
+}
+
+/// Find `exe_name` inside `dir`, checking one level of subdirectories since
+/// release archives commonly wrap their contents in a top-level folder
+fn find_new_executable(dir: &Path, exe_name: &str) -> Result<PathBuf> {
+    let direct = dir.join(exe_name);
+    if direct.exists() {
+        return Ok(direct);
+    }
+
+    if let Ok(entries) = std::fs::read_dir(dir) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                let nested = path.join(exe_name);
+                if nested.exists() {
+                    return Ok(nested);
+                }
+            }
+        }
+    }
+
+    Err(RezToolsError::UpdateError(format!(
+        "Could not find '{}' in the downloaded update archive",
+        exe_name
+    )))
+}
+
+/// Replace `current_exe` with `new_exe` using a rename-aside strategy, so the
+/// swap works even while `current_exe` is the running process (required on
+/// Windows, where the running executable can't be overwritten in place)
+async fn install_new_executable(current_exe: &Path, new_exe: &Path) -> Result<()> {
+    let old_aside = current_exe.with_extension("old");
+
+    // Stage the copy beside `current_exe` so the swap itself is a rename and
+    // `current_exe` never holds a partially written file
+    let staged = current_exe.with_extension("new");
+    tokio::fs::copy(new_exe, &staged).await.map_err(|e| {
+        RezToolsError::UpdateError(format!("Failed to stage new executable: {}", e))
+    })?;
+
+    if old_aside.exists() {
+        let _ = tokio::fs::remove_file(&old_aside).await;
+    }
+    tokio::fs::rename(current_exe, &old_aside).await.map_err(|e| {
+        RezToolsError::UpdateError(format!("Failed to move current executable aside: {}", e))
+    })?;
+
+    if let Err(e) = tokio::fs::rename(&staged, current_exe).await {
+        // Best-effort rollback so a failed install doesn't leave no executable behind
+        let _ = tokio::fs::rename(&old_aside, current_exe).await;
+        let _ = tokio::fs::remove_file(&staged).await;
+        return Err(RezToolsError::UpdateError(format!(
+            "Failed to install new executable: {}",
+            e
+        )));
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut permissions = tokio::fs::metadata(current_exe).await?.permissions();
+        permissions.set_mode(0o755);
+        tokio::fs::set_permissions(current_exe, permissions).await?;
+    }
+
+    if cfg!(windows) {
+        debug!(
+            "Previous executable preserved at {} (Windows can't delete a running exe)",
+            old_aside.display()
+        );
+    } else {
+        let _ = tokio::fs::remove_file(&old_aside).await;
+    }
+
+    Ok(())
+}
+
+/// Parse a dotted version string like "1.2.3" into a comparable tuple,
+/// ignoring any pre-release/build metadata suffix
+fn parse_version(version: &str) -> Option<(u64, u64, u64)> {
+    let core = version.split(['-', '+']).next().unwrap_or(version);
+    let mut parts = core.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next().unwrap_or("0").parse().ok()?;
+    let patch = parts.next().unwrap_or("0").parse().ok()?;
+    Some((major, minor, patch))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_version_basic() {
+        assert_eq!(parse_version("1.2.3"), Some((1, 2, 3)));
+    }
+
+    #[test]
+    fn test_parse_version_strips_prerelease_suffix() {
+        assert_eq!(parse_version("1.2.3-beta.1"), Some((1, 2, 3)));
+        assert_eq!(parse_version("1.2.3+build.5"), Some((1, 2, 3)));
+    }
+
+    #[test]
+    fn test_parse_version_fills_missing_components() {
+        assert_eq!(parse_version("2"), Some((2, 0, 0)));
+        assert_eq!(parse_version("2.1"), Some((2, 1, 0)));
+    }
+
+    #[test]
+    fn test_parse_version_rejects_non_numeric() {
+        assert_eq!(parse_version("latest"), None);
+    }
+
+    #[test]
+    fn test_asset_name_for_platform_uses_zip_on_windows() {
+        let platform = Platform {
+            os: "windows".to_string(),
+            arch: "x86_64".to_string(),
+            target_triple: "x86_64-windows".to_string(),
+        };
+        assert_eq!(
+            asset_name_for_platform(&platform),
+            "rez-tools-x86_64-windows.zip"
+        );
+    }
+
+    #[test]
+    fn test_asset_name_for_platform_uses_tar_gz_elsewhere() {
+        let platform = Platform {
+            os: "linux".to_string(),
+            arch: "x86_64".to_string(),
+            target_triple: "x86_64-linux".to_string(),
+        };
+        assert_eq!(
+            asset_name_for_platform(&platform),
+            "rez-tools-x86_64-linux.tar.gz"
+        );
+    }
+
+    #[test]
+    fn test_find_new_executable_checks_nested_directory() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let nested = temp_dir.path().join("rez-tools-x86_64-linux");
+        std::fs::create_dir_all(&nested).unwrap();
+        std::fs::write(nested.join("rez-tools"), "fake binary").unwrap();
+
+        let found = find_new_executable(temp_dir.path(), "rez-tools").unwrap();
+        assert_eq!(found, nested.join("rez-tools"));
+    }
+
+    #[test]
+    fn test_find_new_executable_missing_returns_error() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let result = find_new_executable(temp_dir.path(), "rez-tools");
+        assert!(result.is_err());
+    }
+}